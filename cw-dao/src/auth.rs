@@ -0,0 +1,86 @@
+use cosmwasm_std::{Addr, Deps, Env, StdResult, Storage};
+
+use crate::error::ContractError;
+use crate::state::{Role, OWNERSHIP, ROLES};
+
+/// 주소의 역할 보유 여부 (오너, 컨트랙트 자신은 항상 Admin)
+pub fn has_role(deps: Deps, env: &Env, address: &Addr, role: &Role) -> StdResult<bool> {
+    if *role == Role::Admin
+        && (env.contract.address == *address || is_owner(deps.storage, address)?)
+    {
+        return Ok(true);
+    }
+    let roles = ROLES.may_load(deps.storage, address)?.unwrap_or_default();
+
+    Ok(roles.contains(role))
+}
+
+/// 역할 중 하나라도 가지고 있는지 여부
+pub fn has_any_role(deps: Deps, env: &Env, address: &Addr, roles: &[Role]) -> StdResult<bool> {
+    for role in roles {
+        if has_role(deps, env, address, role)? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// 역할 체크 (하나라도 가지고 있으면 허용)
+pub fn only_roles(
+    deps: Deps,
+    env: &Env,
+    sender: &Addr,
+    roles: &[Role],
+) -> Result<(), ContractError> {
+    if !has_any_role(deps, env, sender, roles)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    Ok(())
+}
+
+/// 오너 여부
+pub fn is_owner(storage: &dyn Storage, address: &Addr) -> StdResult<bool> {
+    let ownership = OWNERSHIP.load(storage)?;
+
+    Ok(ownership.owner.as_ref() == Some(address))
+}
+
+/// 오너 체크
+pub fn only_owner(storage: &dyn Storage, sender: &Addr) -> Result<(), ContractError> {
+    let ownership = OWNERSHIP.load(storage)?;
+    match ownership.owner {
+        None => Err(ContractError::NoOwner {}),
+        Some(owner) if owner != *sender => Err(ContractError::Unauthorized {}),
+        Some(_) => Ok(()),
+    }
+}
+
+/// 역할 부여, 새로 부여된 경우 true
+pub fn grant_role(storage: &mut dyn Storage, address: &Addr, role: Role) -> StdResult<bool> {
+    let mut roles = ROLES.may_load(storage, address)?.unwrap_or_default();
+    if roles.contains(&role) {
+        return Ok(false);
+    }
+    roles.push(role);
+    ROLES.save(storage, address, &roles)?;
+
+    Ok(true)
+}
+
+/// 역할 회수, 가지고 있던 경우 true
+pub fn revoke_role(storage: &mut dyn Storage, address: &Addr, role: &Role) -> StdResult<bool> {
+    let mut roles = ROLES.may_load(storage, address)?.unwrap_or_default();
+    if !roles.contains(role) {
+        return Ok(false);
+    }
+    roles.retain(|r| r != role);
+    if roles.is_empty() {
+        ROLES.remove(storage, address);
+    } else {
+        ROLES.save(storage, address, &roles)?;
+    }
+
+    Ok(true)
+}
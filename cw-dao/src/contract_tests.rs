@@ -2,16 +2,33 @@
 
 use std::vec;
 
-use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-use cosmwasm_std::{from_binary, Addr, Api, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, Addr, Api, BankMsg, ContractResult, CosmosMsg, Decimal, Deps,
+    DepsMut, Env, MessageInfo, Order, OwnedDeps, Response, StdResult, SubMsg, SystemError,
+    SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use cw2::set_contract_version;
+use cw721::{Cw721QueryMsg, NumTokensResponse, OwnerOfResponse, TokensResponse};
+use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration, Scheduled};
 
-use crate::execute::{execute, instantiate};
+use crate::execute::{execute, instantiate, migrate};
 use crate::msg::{
-    ConfigResponse, ExecuteMsg, InstantiateMsg, PropsalResponse, PropsalResultResponse,
-    PropsalsResponse, QueryMsg,
+    ConfigField, ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, PendingOwnerResponse,
+    ProposalCountResponse, PropsalResponse, PropsalResultResponse, PropsalsResponse, QueryMsg,
+    RecoverPropsal, RoleMembersResponse, RolesResponse, VoteResponse, VotesResponse,
 };
 use crate::query::query;
-use crate::state::{Candidate, Config, Propsal, PropsalStatus, Vote};
+use crate::state::{
+    Candidate, CandidateTally, Config, CreditAllocation, DepositDenom, DepositRefundPolicy,
+    DepositStatus, Ownership, PowerSource, ProposalDeposit, Propsal, PropsalOutcome,
+    PropsalResultStatus, PropsalStatus, Quorum, Role, RoundCount, RunoffRound, TieBreaker, Turnout,
+    Vote, VoteOption, VoteTransfer, VotingStrategy, PROPSALS, PROPSAL_RESULTS, TALLIES,
+};
 use crate::ContractError;
 
 fn setup_contract(
@@ -21,7 +38,16 @@ fn setup_contract(
 ) -> Result<(Response, Env), ContractError> {
     let msg = InstantiateMsg {
         owner: info.sender.to_string(),
-        max_duration_seconds: 2629743,
+        max_duration: Duration::Time(2629743),
+        max_duration_secondary: None,
+        quorum: None,
+        threshold: None,
+        timelock_seconds: 0,
+        guardians: vec![],
+        nft_address: None,
+        unbonding_period: None,
+        voting_strategy: None,
+        proposal_deposit: None,
     };
     let res = instantiate(deps, env.clone(), info, msg)?;
     env.block.height += 1;
@@ -34,17 +60,20 @@ fn test_instantiate() {
     let mut deps = mock_dependencies();
     let env = mock_env();
     let owner = "owner";
-    let info = mock_info(&owner, &[]);
+    let info = mock_info(owner, &[]);
     let (_, env) = setup_contract(deps.as_mut(), env.clone(), info).unwrap();
 
-    let max_duration_seconds = 2629743;
+    let max_duration = Duration::Time(2629743);
 
     let query_config = query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap();
-    let response_config: ConfigResponse = from_binary(&query_config).unwrap();
+    let response_config: ConfigResponse = from_json(&query_config).unwrap();
     let config = response_config.config;
 
-    assert_eq!(owner, config.owner);
-    assert_eq!(max_duration_seconds, config.max_duration_seconds);
+    let query_ownership = query(deps.as_ref(), env.clone(), QueryMsg::Ownership {}).unwrap();
+    let ownership: Ownership = from_json(&query_ownership).unwrap();
+
+    assert_eq!(Some(Addr::unchecked(owner)), ownership.owner);
+    assert_eq!(max_duration, config.max_duration);
 }
 
 #[test]
@@ -52,28 +81,66 @@ fn test_update_config() {
     let mut deps = mock_dependencies();
     let env = mock_env();
     let owner = "owner";
-    let info = mock_info(&owner, &[]);
+    let info = mock_info(owner, &[]);
     let (_, env) = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
 
-    let owner = String::from("update-owner");
-    let max_duration_seconds = 2629745;
+    let max_duration = Duration::Height(100);
 
     let config = Config {
         start_blocknumber: env.block.height - 1,
-        owner: Addr::unchecked(owner),
-        max_duration_seconds,
+        max_duration,
+        max_duration_secondary: None,
+        quorum: Some(Quorum::AbsolutePower { power: 1 }),
+        threshold: Some(Decimal::percent(50)),
+        timelock_seconds: 100,
+        nft_address: Some(Addr::unchecked("nft")),
+        unbonding_period: None,
+        voting_strategy: VotingStrategy::Plurality,
+        proposal_deposit: None,
     };
 
     let msg = ExecuteMsg::UpdateConfig {
-        max_duration_seconds: Some(config.max_duration_seconds),
-        owner: Some(config.owner.to_string()),
+        max_duration: Some(config.max_duration),
+        max_duration_secondary: None,
+        quorum: config.quorum.clone(),
+        threshold: config.threshold,
+        timelock_seconds: Some(config.timelock_seconds),
+        nft_address: Some("nft".to_string()),
+        unbonding_period: None,
+        voting_strategy: None,
+        proposal_deposit: None,
     };
     _ = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
 
     let res = query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap();
-    let config_response: ConfigResponse = from_binary(&res).unwrap();
+    let config_response: ConfigResponse = from_json(&res).unwrap();
 
     assert_eq!(config.clone(), config_response.config.clone());
+
+    // 지정한 정족수, 통과 기준을 없음으로 되돌림
+    let msg = ExecuteMsg::ClearConfig {
+        fields: vec![ConfigField::Quorum, ConfigField::Threshold],
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("stranger", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    _ = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let res = query(deps.as_ref(), env, QueryMsg::Config {}).unwrap();
+    let config_response: ConfigResponse = from_json(&res).unwrap();
+    assert_eq!(
+        Config {
+            quorum: None,
+            threshold: None,
+            ..config
+        },
+        config_response.config
+    );
 }
 
 #[test]
@@ -81,16 +148,17 @@ fn test_add_propsal() {
     let mut deps = mock_dependencies();
     let mut env = mock_env();
     let owner = "owner";
-    let info = mock_info(&owner, &[]);
+    let info = mock_info(owner, &[]);
     _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
 
     let title = String::from("test-propsal");
     let status = PropsalStatus::Enabled;
     let nft_address = String::from("nft");
-    let expiration = env.block.time.seconds() + 100;
+    let expiration = Expiration::AtTime(env.block.time.plus_seconds(100));
     let candidates = vec![Candidate {
         id: 1,
         name: ("candiate1").to_string(),
+        msgs: vec![],
     }];
 
     let msg = ExecuteMsg::AddPropsal {
@@ -99,6 +167,14 @@ fn test_add_propsal() {
         nft_address: nft_address.clone(),
         expiration,
         candidates: candidates.clone(),
+        allow_revoting: false,
+        tie_breaker: None,
+        voting_starts_at: None,
+        quorum: None,
+        threshold: None,
+        power_source: None,
+        voting_strategy: None,
+        seats: None,
     };
 
     env.block.height += 1;
@@ -114,9 +190,8 @@ fn test_add_propsal() {
     )
     .unwrap();
 
-    let propsals_response: PropsalsResponse = from_binary(&res).unwrap();
+    let propsals_response: PropsalsResponse = from_json(&res).unwrap();
     let valid_nft_address = deps.api.addr_validate(&nft_address).unwrap();
-    let votes = vec![];
 
     let propsal = Propsal {
         id: 1,
@@ -125,18 +200,146 @@ fn test_add_propsal() {
         nft_address: valid_nft_address,
         expiration,
         candidates,
-        votes,
+        allow_revoting: false,
+        tie_breaker: None,
+        voting_starts_at: None,
+        start_height: env.block.height,
+        power_source: PowerSource::TokenIds,
+        voting_strategy: VotingStrategy::Plurality,
+        seats: 1,
+        quorum: None,
+        threshold: None,
+        total_power: 0,
+        voter_count: 0,
+        vote_count: 0,
+        executable_at: None,
+        veto_reason: None,
+        proposer: Addr::unchecked(owner),
+        deposit: None,
     };
 
     assert_eq!(Some(vec![propsal]), Some(propsals_response.propsals));
 }
 
+#[test]
+fn test_add_propsal_increments_id() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+
+    env.block.height += 1;
+    _ = add_propsal(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    env.block.height += 1;
+    _ = add_propsal(deps.as_mut(), env.clone(), info.clone()).unwrap();
+
+    let res = query(deps.as_ref(), env.clone(), QueryMsg::ProposalCount {}).unwrap();
+    let count_response: ProposalCountResponse = from_json(&res).unwrap();
+    assert_eq!(3, count_response.next_id);
+    assert_eq!(2, count_response.total);
+
+    let ids: Vec<u64> = PROPSALS
+        .keys(&deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()
+        .unwrap();
+    assert_eq!(vec![1, 2], ids);
+}
+
+#[test]
+fn test_migrate_v0_1() {
+    #[cw_serde]
+    struct LegacyConfig {
+        start_blocknumber: u64,
+        max_duration_seconds: u64,
+        owner: Addr,
+    }
+    #[cw_serde]
+    struct LegacyVote {
+        power: u64,
+        voter: Addr,
+        propsal_id: u64,
+        candidate_id: u64,
+    }
+    #[cw_serde]
+    struct LegacyPropsal {
+        id: u64,
+        title: String,
+        status: PropsalStatus,
+        nft_address: Addr,
+        expiration: u64,
+        votes: Vec<LegacyVote>,
+        candidates: Vec<Candidate>,
+    }
+
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    _ = setup_contract(deps.as_mut(), env.clone(), info).unwrap();
+
+    // 0.1.x: 아이디 1 에 마지막 제안만 남고, 같은 투표자의 중복 투표가 저장됨
+    set_contract_version(&mut deps.storage, "nft-dao", "0.1.0").unwrap();
+    let legacy_config = LegacyConfig {
+        start_blocknumber: env.block.height,
+        max_duration_seconds: 2629743,
+        owner: Addr::unchecked(owner),
+    };
+    Item::<LegacyConfig>::new("config")
+        .save(&mut deps.storage, &legacy_config)
+        .unwrap();
+    let legacy_vote = LegacyVote {
+        power: 1,
+        voter: Addr::unchecked("voter"),
+        propsal_id: 1,
+        candidate_id: 1,
+    };
+    let legacy = LegacyPropsal {
+        id: 1,
+        title: String::from("last-propsal"),
+        status: PropsalStatus::Enabled,
+        nft_address: Addr::unchecked("nft"),
+        expiration: env.block.time.seconds() + 100,
+        votes: vec![legacy_vote.clone(), legacy_vote],
+        candidates: vec![Candidate {
+            id: 1,
+            name: ("candiate1").to_string(),
+            msgs: vec![],
+        }],
+    };
+    Map::<u64, LegacyPropsal>::new("propsals")
+        .save(&mut deps.storage, 1, &legacy)
+        .unwrap();
+
+    let msg = MigrateMsg {
+        recover_propsals: vec![RecoverPropsal {
+            title: String::from("lost-propsal"),
+            status: PropsalStatus::Enabled,
+            nft_address: String::from("nft"),
+            expiration: env.block.time.seconds() + 100,
+            candidates: legacy.candidates.clone(),
+        }],
+    };
+    _ = migrate(deps.as_mut(), env.clone(), msg).unwrap();
+
+    let propsal = PROPSALS.load(&deps.storage, 1).unwrap();
+    assert_eq!((1, 1), (propsal.total_power, propsal.voter_count));
+    assert_eq!(1, TALLIES.load(&deps.storage, (1, 1)).unwrap().power);
+    let recovered = PROPSALS.load(&deps.storage, 2).unwrap();
+    assert_eq!("lost-propsal", recovered.title);
+
+    let res = query(deps.as_ref(), env, QueryMsg::ProposalCount {}).unwrap();
+    let count_response: ProposalCountResponse = from_json(&res).unwrap();
+    assert_eq!(3, count_response.next_id);
+    assert_eq!(2, count_response.total);
+}
+
 #[test]
 fn test_update_propsal() {
     let mut deps = mock_dependencies();
     let mut env = mock_env();
     let owner = "owner";
-    let info: MessageInfo = mock_info(&owner, &[]);
+    let info: MessageInfo = mock_info(owner, &[]);
     _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
     _ = add_propsal(deps.as_mut(), env.clone(), info.clone());
 
@@ -144,10 +347,11 @@ fn test_update_propsal() {
     let title = String::from("update-propsal");
     let status = PropsalStatus::Disabled;
     let nft_address = String::from("nft");
-    let expiration = env.block.time.seconds() + 100;
+    let expiration = Expiration::AtTime(env.block.time.plus_seconds(100));
     let candidates = vec![Candidate {
         id: 1,
         name: ("u-candiate1").to_string(),
+        msgs: vec![],
     }];
 
     let msg = ExecuteMsg::UpdatePropsal {
@@ -155,7 +359,7 @@ fn test_update_propsal() {
         title: Some(title.clone()),
         status: Some(status.clone()),
         nft_address: Some(nft_address.clone()),
-        expiration: Some(expiration.clone()),
+        expiration: Some(expiration),
         candidates: Some(candidates.clone()),
     };
 
@@ -163,9 +367,8 @@ fn test_update_propsal() {
     _ = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
 
     let res = query(deps.as_ref(), env.clone(), QueryMsg::Propsal { id }).unwrap();
-    let propsal_response: PropsalResponse = from_binary(&res).unwrap();
+    let propsal_response: PropsalResponse = from_json(&res).unwrap();
     let valid_nft_address = deps.api.addr_validate(&nft_address).unwrap();
-    let votes = vec![];
 
     let propsal = Propsal {
         id: 1,
@@ -174,7 +377,22 @@ fn test_update_propsal() {
         nft_address: valid_nft_address,
         expiration,
         candidates,
-        votes,
+        allow_revoting: false,
+        tie_breaker: None,
+        voting_starts_at: None,
+        start_height: mock_env().block.height,
+        power_source: PowerSource::TokenIds,
+        voting_strategy: VotingStrategy::Plurality,
+        seats: 1,
+        quorum: None,
+        threshold: None,
+        total_power: 0,
+        voter_count: 0,
+        vote_count: 0,
+        executable_at: None,
+        veto_reason: None,
+        proposer: Addr::unchecked(owner),
+        deposit: None,
     };
 
     assert_eq!(propsal, propsal_response.propsal);
@@ -185,7 +403,7 @@ fn test_remove_propsal() {
     let mut deps = mock_dependencies();
     let mut env = mock_env();
     let owner = "owner";
-    let info = mock_info(&owner, &[]);
+    let info = mock_info(owner, &[]);
     _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
     _ = add_propsal(deps.as_mut(), env.clone(), info.clone());
 
@@ -204,7 +422,7 @@ fn test_remove_propsal() {
     )
     .unwrap();
 
-    let propsals_response: PropsalsResponse = from_binary(&res).unwrap();
+    let propsals_response: PropsalsResponse = from_json(&res).unwrap();
 
     assert_eq!(Some(vec![]), Some(propsals_response.propsals));
 }
@@ -214,22 +432,25 @@ fn test_execute_vote() {
     let mut deps = mock_dependencies();
     let mut env = mock_env();
     let owner = "owner";
-    let info = mock_info(&owner, &[]);
+    let info = mock_info(owner, &[]);
+    mock_nft_owners(&mut deps, vec![("1", owner)]);
     _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
     _ = add_propsal(deps.as_mut(), env.clone(), info.clone());
 
     let title = String::from("test-propsal");
     let status = PropsalStatus::Enabled;
     let nft_address = String::from("nft");
-    let expiration = env.block.time.seconds() + 100;
+    let expiration = Expiration::AtTime(env.block.time.plus_seconds(100));
     let candidates = vec![Candidate {
         id: 1,
         name: ("candiate1").to_string(),
+        msgs: vec![],
     }];
 
     let msg = ExecuteMsg::ExecuteVote {
         propsal_id: 1,
         candidate_id: 1,
+        token_ids: vec!["1".to_string()],
     };
 
     env.block.height += 1;
@@ -237,17 +458,9 @@ fn test_execute_vote() {
 
     let res = query(deps.as_ref(), env.clone(), QueryMsg::Propsal { id: 1 }).unwrap();
 
-    let propsal_response: PropsalResponse = from_binary(&res).unwrap();
+    let propsal_response: PropsalResponse = from_json(&res).unwrap();
 
     let valid_nft_address = deps.api.addr_validate(&nft_address).unwrap();
-    let vote = Vote {
-        power: 1,
-        voter: Addr::unchecked(owner),
-        propsal_id: 1,
-        candidate_id: 1,
-    };
-    let votes = vec![vote];
-
     let propsal = Propsal {
         id: 1,
         title,
@@ -255,10 +468,305 @@ fn test_execute_vote() {
         nft_address: valid_nft_address,
         expiration,
         candidates,
-        votes,
+        allow_revoting: false,
+        tie_breaker: None,
+        voting_starts_at: None,
+        start_height: mock_env().block.height,
+        power_source: PowerSource::TokenIds,
+        voting_strategy: VotingStrategy::Plurality,
+        seats: 1,
+        quorum: None,
+        threshold: None,
+        total_power: 1,
+        voter_count: 1,
+        vote_count: 1,
+        executable_at: None,
+        veto_reason: None,
+        proposer: Addr::unchecked(owner),
+        deposit: None,
     };
 
     assert_eq!(propsal, propsal_response.propsal);
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Vote {
+            propsal_id: 1,
+            voter: owner.to_string(),
+        },
+    )
+    .unwrap();
+    let vote_response: VoteResponse = from_json(&res).unwrap();
+    let vote = Vote {
+        power: 1,
+        voter: Addr::unchecked(owner),
+        propsal_id: 1,
+        candidate_id: 1,
+        token_ids: vec!["1".to_string()],
+        credits: vec![],
+        ranking: vec![],
+        approvals: vec![],
+    };
+
+    assert_eq!(Some(vote), vote_response.vote);
+}
+
+#[test]
+fn test_execute_vote_not_token_owner() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    mock_nft_owners(&mut deps, vec![("1", "holder")]);
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    _ = add_propsal(deps.as_mut(), env.clone(), info.clone());
+
+    env.block.height += 1;
+    let err = execute_vote(deps.as_mut(), env.clone(), info.clone(), 1, 1, &["1"]).unwrap_err();
+    assert!(matches!(err, ContractError::NotTokenOwner { token_id } if token_id == "1"));
+
+    let err = execute_vote(deps.as_mut(), env.clone(), info.clone(), 1, 1, &[]).unwrap_err();
+    assert!(matches!(err, ContractError::NoVotingPower {}));
+}
+
+#[test]
+fn test_execute_vote_token_votes_once() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    mock_nft_owners(&mut deps, vec![("1", "holder"), ("2", "holder")]);
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    _ = add_propsal(deps.as_mut(), env.clone(), info.clone());
+
+    let holder = mock_info("holder", &[]);
+    env.block.height += 1;
+    _ = execute_vote(
+        deps.as_mut(),
+        env.clone(),
+        holder.clone(),
+        1,
+        1,
+        &["1", "1"],
+    )
+    .unwrap();
+
+    let res = query(deps.as_ref(), env.clone(), QueryMsg::Propsal { id: 1 }).unwrap();
+    let propsal_response: PropsalResponse = from_json(&res).unwrap();
+    assert_eq!(1, propsal_response.propsal.total_power);
+
+    env.block.height += 1;
+    let err = execute_vote(deps.as_mut(), env.clone(), holder, 1, 1, &["2"]).unwrap_err();
+    assert!(matches!(err, ContractError::AlreadyVoted {}));
+}
+
+#[test]
+fn test_execute_vote_revoting() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    mock_nft_owners(&mut deps, vec![("1", "holder"), ("2", "holder")]);
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+
+    let msg = ExecuteMsg::AddPropsal {
+        title: String::from("revoting-propsal"),
+        status: PropsalStatus::Enabled,
+        nft_address: String::from("nft"),
+        expiration: Expiration::AtTime(env.block.time.plus_seconds(100)),
+        candidates: vec![
+            Candidate {
+                id: 1,
+                name: ("candiate1").to_string(),
+                msgs: vec![],
+            },
+            Candidate {
+                id: 2,
+                name: ("candiate2").to_string(),
+                msgs: vec![],
+            },
+        ],
+        allow_revoting: true,
+        tie_breaker: None,
+        voting_starts_at: None,
+        quorum: None,
+        threshold: None,
+        power_source: None,
+        voting_strategy: None,
+        seats: None,
+    };
+    env.block.height += 1;
+    _ = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    let holder = mock_info("holder", &[]);
+    env.block.height += 1;
+    _ = execute_vote(deps.as_mut(), env.clone(), holder.clone(), 1, 1, &["1"]).unwrap();
+
+    // 후보 변경 + 새 토큰 추가, 이미 투표한 토큰은 다시 세지 않음
+    env.block.height += 1;
+    _ = execute_vote(deps.as_mut(), env.clone(), holder, 1, 2, &["1", "2"]).unwrap();
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Votes {
+            propsal_id: 1,
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let votes_response: VotesResponse = from_json(&res).unwrap();
+    let vote = Vote {
+        power: 2,
+        voter: Addr::unchecked("holder"),
+        propsal_id: 1,
+        candidate_id: 2,
+        token_ids: vec!["1".to_string(), "2".to_string()],
+        credits: vec![],
+        ranking: vec![],
+        approvals: vec![],
+    };
+    assert_eq!(vec![vote.clone()], votes_response.votes);
+
+    // 후보별 인덱스와 득표 집계가 함께 갱신
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::CandidateVotes {
+            propsal_id: 1,
+            candidate_id: 1,
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let votes_response: VotesResponse = from_json(&res).unwrap();
+    assert!(votes_response.votes.is_empty());
+
+    let res = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::VoterVotes {
+            voter: "holder".to_string(),
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let votes_response: VotesResponse = from_json(&res).unwrap();
+    assert_eq!(vec![vote], votes_response.votes);
+
+    assert_eq!(0, TALLIES.load(&deps.storage, (1, 1)).unwrap().power);
+    assert_eq!(2, TALLIES.load(&deps.storage, (1, 2)).unwrap().power);
+    let propsal = PROPSALS.load(&deps.storage, 1).unwrap();
+    assert_eq!((2, 1), (propsal.total_power, propsal.voter_count));
+}
+
+#[test]
+fn test_execute_vote_closed() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    mock_nft_owners(&mut deps, vec![("1", owner)]);
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    _ = add_propsal(deps.as_mut(), env.clone(), info.clone());
+
+    env.block.height += 1;
+    let err = execute_vote(deps.as_mut(), env.clone(), info.clone(), 1, 2, &["1"]).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::InvalidCandidate { candidate_id: 2 }
+    ));
+
+    let msg = ExecuteMsg::UpdatePropsal {
+        id: 1,
+        title: None,
+        status: Some(PropsalStatus::Disabled),
+        nft_address: None,
+        expiration: None,
+        candidates: None,
+    };
+    _ = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    env.block.height += 1;
+    let err = execute_vote(deps.as_mut(), env.clone(), info.clone(), 1, 1, &["1"]).unwrap_err();
+    assert!(matches!(err, ContractError::StatusDisabled {}));
+
+    let msg = ExecuteMsg::UpdatePropsal {
+        id: 1,
+        title: None,
+        status: Some(PropsalStatus::Enabled),
+        nft_address: None,
+        expiration: None,
+        candidates: None,
+    };
+    env.block.height += 1;
+    _ = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    // 마감기한 이후 투표 불가
+    env.block.height += 1;
+    env.block.time = env.block.time.plus_seconds(100);
+    let err = execute_vote(deps.as_mut(), env, info, 1, 1, &["1"]).unwrap_err();
+    assert!(matches!(err, ContractError::VotingClosed {}));
+}
+
+#[test]
+fn test_update_propsal_after_vote() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    mock_nft_owners(&mut deps, vec![("1", owner)]);
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    _ = add_propsal(deps.as_mut(), env.clone(), info.clone());
+
+    env.block.height += 1;
+    _ = execute_vote(deps.as_mut(), env.clone(), info.clone(), 1, 1, &["1"]).unwrap();
+
+    // 투표 후에는 후보와 nft 변경 불가
+    let update = |nft_address: Option<String>, candidates: Option<Vec<Candidate>>| {
+        ExecuteMsg::UpdatePropsal {
+            id: 1,
+            title: Some("updated".to_string()),
+            status: None,
+            nft_address,
+            expiration: None,
+            candidates,
+        }
+    };
+    let candidates = vec![Candidate {
+        id: 1,
+        name: "candiate1".to_string(),
+        msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "attacker".to_string(),
+            amount: coins(100, "ujuno"),
+        })],
+    }];
+    env.block.height += 1;
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        update(None, Some(candidates)),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::PropsalLocked {}));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        update(Some("nft2".to_string()), None),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::PropsalLocked {}));
+
+    _ = execute(deps.as_mut(), env.clone(), info, update(None, None)).unwrap();
+    let propsal = PROPSALS.load(&deps.storage, 1).unwrap();
+    assert_eq!("updated", propsal.title);
+    assert!(propsal.candidates[0].msgs.is_empty());
 }
 
 #[test]
@@ -266,10 +774,11 @@ fn test_propsal_result() {
     let mut deps = mock_dependencies();
     let env = mock_env();
     let owner = "owner";
-    let info = mock_info(&owner, &[]);
+    let info = mock_info(owner, &[]);
+    mock_nft_owners(&mut deps, vec![("1", owner)]);
     _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
     _ = add_propsal(deps.as_mut(), env.clone(), info.clone());
-    _ = execute_vote(deps.as_mut(), env.clone(), info.clone(), 1, 1);
+    _ = execute_vote(deps.as_mut(), env.clone(), info.clone(), 1, 1, &["1"]);
 
     let res = query(
         deps.as_ref(),
@@ -278,56 +787,1705 @@ fn test_propsal_result() {
     )
     .unwrap();
 
-    let propsal_result_response: PropsalResultResponse = from_binary(&res).unwrap();
+    let propsal_result_response: PropsalResultResponse = from_json(&res).unwrap();
 
     let candidate = Candidate {
         id: 1,
-        name: String::from(""),
+        name: ("candiate1").to_string(),
+        msgs: vec![],
     };
 
-    assert_eq!(candidate, propsal_result_response.propsal_result.winner);
+    let propsal_result = propsal_result_response.propsal_result;
+    assert_eq!(PropsalOutcome::Winner {}, propsal_result.outcome);
+    assert_eq!(vec![candidate], propsal_result.winners);
+    assert_eq!(
+        Turnout {
+            total_power: 1,
+            voter_count: 1,
+            total_supply: None,
+        },
+        propsal_result.turnout
+    );
 }
 
-fn add_propsal(deps: DepsMut, mut env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-    let title = String::from("test-propsal");
-    let status = PropsalStatus::Enabled;
-    let nft_address = String::from("nft");
-    let expiration = env.block.time.seconds() + 100;
-    let candidates = vec![Candidate {
-        id: 1,
-        name: ("candiate1").to_string(),
-    }];
+#[test]
+fn test_propsal_result_no_votes() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    _ = add_propsal(deps.as_mut(), env.clone(), info.clone());
+
+    let res = query(deps.as_ref(), env, QueryMsg::PropsalResult { id: 1 }).unwrap();
+    let propsal_result_response: PropsalResultResponse = from_json(&res).unwrap();
+    let propsal_result = propsal_result_response.propsal_result;
+
+    assert_eq!(PropsalResultStatus::Rejected, propsal_result.status);
+    assert_eq!(PropsalOutcome::NoVotes {}, propsal_result.outcome);
+    assert!(propsal_result.winners.is_empty());
+    assert_eq!(0, propsal_result.tallies[0].power);
+}
+
+#[test]
+fn test_propsal_result_tie_breaker() {
+    let cases = vec![
+        (
+            None,
+            PropsalOutcome::Tie {
+                candidate_ids: vec![1, 2],
+            },
+            None,
+        ),
+        (
+            Some(TieBreaker::LowestCandidateId),
+            PropsalOutcome::Winner {},
+            Some(1),
+        ),
+        (
+            Some(TieBreaker::EarliestToReach),
+            PropsalOutcome::Winner {},
+            Some(2),
+        ),
+    ];
+
+    for (tie_breaker, outcome, winner_id) in cases {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let owner = "owner";
+        let info = mock_info(owner, &[]);
+        mock_nft_owners(&mut deps, vec![("1", "voter1"), ("2", "voter2")]);
+        _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+
+        let msg = ExecuteMsg::AddPropsal {
+            title: String::from("tie-propsal"),
+            status: PropsalStatus::Enabled,
+            nft_address: String::from("nft"),
+            expiration: Expiration::AtTime(env.block.time.plus_seconds(100)),
+            candidates: vec![
+                Candidate {
+                    id: 1,
+                    name: ("candiate1").to_string(),
+                    msgs: vec![],
+                },
+                Candidate {
+                    id: 2,
+                    name: ("candiate2").to_string(),
+                    msgs: vec![],
+                },
+            ],
+            allow_revoting: false,
+            tie_breaker,
+            voting_starts_at: None,
+            quorum: None,
+            threshold: None,
+            power_source: None,
+            voting_strategy: None,
+            seats: None,
+        };
+        env.block.height += 1;
+        _ = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        // 후보 2 가 먼저 1 power 에 도달
+        env.block.height += 1;
+        _ = execute_vote(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("voter2", &[]),
+            1,
+            2,
+            &["2"],
+        )
+        .unwrap();
+        env.block.height += 1;
+        _ = execute_vote(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("voter1", &[]),
+            1,
+            1,
+            &["1"],
+        )
+        .unwrap();
+
+        let res = query(deps.as_ref(), env, QueryMsg::PropsalResult { id: 1 }).unwrap();
+        let propsal_result_response: PropsalResultResponse = from_json(&res).unwrap();
+        let propsal_result = propsal_result_response.propsal_result;
+
+        assert_eq!(outcome, propsal_result.outcome);
+        assert_eq!(
+            winner_id,
+            propsal_result.winners.first().map(|winner| winner.id)
+        );
+    }
+}
+
+#[test]
+fn test_earliest_to_reach_ignores_removed_votes() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let info = mock_info("owner", &[]);
+    mock_nft_owners(
+        &mut deps,
+        vec![("1", "voter1"), ("2", "voter2"), ("3", "voter3")],
+    );
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
 
     let msg = ExecuteMsg::AddPropsal {
-        title: title.clone(),
-        status: status.clone(),
-        nft_address: nft_address.clone(),
-        expiration,
-        candidates: candidates.clone(),
+        title: String::from("tie-propsal"),
+        status: PropsalStatus::Enabled,
+        nft_address: String::from("nft"),
+        expiration: Expiration::AtTime(env.block.time.plus_seconds(100)),
+        candidates: (1..=3)
+            .map(|id| Candidate {
+                id,
+                name: format!("candidate{}", id),
+                msgs: vec![],
+            })
+            .collect(),
+        allow_revoting: true,
+        tie_breaker: Some(TieBreaker::EarliestToReach),
+        voting_starts_at: None,
+        quorum: None,
+        threshold: None,
+        power_source: None,
+        voting_strategy: None,
+        seats: None,
     };
+    // 후보 1 이 먼저 1 power 에 도달, 재투표로 득표가 줄어도 도달 시점 유지
+    // 득표가 줄었다가 같은 득표로 돌아와도 처음 도달한 시점 기준
+    let cases = [
+        [
+            ("voter1", 1, "1"),
+            ("voter2", 1, "2"),
+            ("voter3", 2, "3"),
+            ("voter2", 3, "2"),
+        ],
+        [
+            ("voter1", 1, "1"),
+            ("voter2", 2, "2"),
+            ("voter3", 1, "3"),
+            ("voter3", 3, "3"),
+        ],
+    ];
+    for (id, ballots) in (1..).zip(cases) {
+        env.block.height += 1;
+        _ = execute(deps.as_mut(), env.clone(), info.clone(), msg.clone()).unwrap();
+        for (voter, candidate_id, token_id) in ballots {
+            env.block.height += 1;
+            _ = execute_vote(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(voter, &[]),
+                id,
+                candidate_id,
+                &[token_id],
+            )
+            .unwrap();
+        }
 
-    let res = execute(deps, env.clone(), info.clone(), msg.clone())?;
+        let res = query(deps.as_ref(), env.clone(), QueryMsg::PropsalResult { id }).unwrap();
+        let propsal_result = from_json::<PropsalResultResponse>(&res)
+            .unwrap()
+            .propsal_result;
+        assert_eq!(
+            vec![1, 1, 1],
+            propsal_result
+                .tallies
+                .iter()
+                .map(|tally| tally.power)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(1),
+            propsal_result.winners.first().map(|winner| winner.id)
+        );
+    }
+}
 
-    env.block.height += 1;
+#[test]
+fn test_propsal_result_quorum_and_threshold() {
+    // (quorum, threshold, status) - nft 4개 중 voter1 2표(후보1), voter2 1표(후보2)
+    let cases = vec![
+        (
+            Some(Quorum::Percentage {
+                percentage: Decimal::percent(80),
+            }),
+            None,
+            PropsalResultStatus::QuorumNotMet,
+        ),
+        (
+            Some(Quorum::AbsolutePower { power: 4 }),
+            None,
+            PropsalResultStatus::QuorumNotMet,
+        ),
+        (
+            Some(Quorum::Percentage {
+                percentage: Decimal::percent(20),
+            }),
+            Some(Decimal::percent(50)),
+            PropsalResultStatus::Passed,
+        ),
+        (
+            None,
+            Some(Decimal::percent(70)),
+            PropsalResultStatus::Rejected,
+        ),
+    ];
 
-    Ok(res)
+    for (quorum, threshold, status) in cases {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let owner = "owner";
+        let info = mock_info(owner, &[]);
+        mock_nft_owners(
+            &mut deps,
+            vec![
+                ("1", "voter1"),
+                ("2", "voter1"),
+                ("3", "voter2"),
+                ("4", "voter3"),
+            ],
+        );
+        _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+
+        let msg = ExecuteMsg::AddPropsal {
+            title: String::from("quorum-propsal"),
+            status: PropsalStatus::Enabled,
+            nft_address: String::from("nft"),
+            expiration: Expiration::AtTime(env.block.time.plus_seconds(100)),
+            candidates: vec![
+                Candidate {
+                    id: 1,
+                    name: ("candiate1").to_string(),
+                    msgs: vec![],
+                },
+                Candidate {
+                    id: 2,
+                    name: ("candiate2").to_string(),
+                    msgs: vec![],
+                },
+            ],
+            allow_revoting: false,
+            tie_breaker: None,
+            voting_starts_at: None,
+            quorum,
+            threshold,
+            power_source: None,
+            voting_strategy: None,
+            seats: None,
+        };
+        env.block.height += 1;
+        _ = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+        env.block.height += 1;
+        let voter1 = mock_info("voter1", &[]);
+        _ = execute_vote(deps.as_mut(), env.clone(), voter1, 1, 1, &["1", "2"]).unwrap();
+        env.block.height += 1;
+        let voter2 = mock_info("voter2", &[]);
+        _ = execute_vote(deps.as_mut(), env.clone(), voter2, 1, 2, &["3"]).unwrap();
+
+        let res = query(deps.as_ref(), env, QueryMsg::PropsalResult { id: 1 }).unwrap();
+        let propsal_result_response: PropsalResultResponse = from_json(&res).unwrap();
+
+        assert_eq!(status, propsal_result_response.propsal_result.status);
+    }
 }
 
-fn execute_vote(
-    deps: DepsMut,
-    mut env: Env,
-    info: MessageInfo,
-    propsal_id: u64,
-    candidate_id: u64,
-) -> Result<Response, ContractError> {
-    let msg = ExecuteMsg::ExecuteVote {
-        propsal_id,
-        candidate_id,
+#[test]
+fn test_invalid_voting_rules() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("owner", &[]);
+    let msg = InstantiateMsg {
+        owner: "owner".to_string(),
+        max_duration: Duration::Time(2629743),
+        max_duration_secondary: None,
+        quorum: Some(Quorum::Percentage {
+            percentage: Decimal::percent(120),
+        }),
+        threshold: None,
+        timelock_seconds: 0,
+        guardians: vec![],
+        nft_address: None,
+        unbonding_period: None,
+        voting_strategy: None,
+        proposal_deposit: None,
     };
+    let err = instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidPercentage {}));
 
-    let res = execute(deps, env.clone(), info.clone(), msg.clone())?;
+    let msg = InstantiateMsg {
+        owner: "owner".to_string(),
+        max_duration: Duration::Time(2629743),
+        max_duration_secondary: None,
+        quorum: None,
+        threshold: Some(Decimal::one()),
+        timelock_seconds: 0,
+        guardians: vec![],
+        nft_address: None,
+        unbonding_period: None,
+        voting_strategy: None,
+        proposal_deposit: None,
+    };
+    let err = instantiate(deps.as_mut(), env, info, msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidPercentage {}));
+}
+
+#[test]
+fn test_finalize() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    mock_nft_owners(&mut deps, vec![("1", owner)]);
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    _ = add_propsal(deps.as_mut(), env.clone(), info.clone());
 
     env.block.height += 1;
+    _ = execute_vote(deps.as_mut(), env.clone(), info.clone(), 1, 1, &["1"]).unwrap();
 
-    Ok(res)
+    // 마감 전에는 확정 불가
+    let anyone = mock_info("anyone", &[]);
+    let msg = ExecuteMsg::Finalize { id: 1 };
+    env.block.height += 1;
+    let err = execute(deps.as_mut(), env.clone(), anyone.clone(), msg.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::VotingNotEnded {}));
+
+    env.block.time = env.block.time.plus_seconds(100);
+    let res = execute(deps.as_mut(), env.clone(), anyone.clone(), msg.clone()).unwrap();
+    assert_eq!("propsal_finalized", res.events[0].ty);
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::PropsalResult { id: 1 },
+    )
+    .unwrap();
+    let propsal_result_response: PropsalResultResponse = from_json(&res).unwrap();
+    let propsal_result = propsal_result_response.propsal_result;
+    assert_eq!(PropsalStatus::Finalized, propsal_result.propsal.status);
+    assert_eq!(
+        vec![CandidateTally {
+            candidate_id: 1,
+            name: ("candiate1").to_string(),
+            power: 1,
+            weight: Decimal::one(),
+        }],
+        propsal_result.tallies
+    );
+    assert_eq!(
+        propsal_result,
+        PROPSAL_RESULTS.load(&deps.storage, 1).unwrap()
+    );
+
+    // 확정 이후 재확정, 수정 불가
+    env.block.height += 1;
+    let err = execute(deps.as_mut(), env.clone(), anyone, msg).unwrap_err();
+    assert!(matches!(err, ContractError::PropsalFinalized {}));
+
+    let msg = ExecuteMsg::UpdatePropsal {
+        id: 1,
+        title: None,
+        status: Some(PropsalStatus::Enabled),
+        nft_address: None,
+        expiration: None,
+        candidates: None,
+    };
+    let err = execute(deps.as_mut(), env, info, msg).unwrap_err();
+    assert!(matches!(err, ContractError::PropsalFinalized {}));
+}
+
+#[test]
+fn test_roles() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = mock_info("owner", &[]);
+    let creator = mock_info("creator", &[]);
+    let manager = mock_info("manager", &[]);
+    (_, env) = setup_contract(deps.as_mut(), env.clone(), owner.clone()).unwrap();
+
+    // Admin 만 역할 부여 가능
+    let grant = |address: &str, role: Role| ExecuteMsg::GrantRole {
+        address: address.to_string(),
+        role,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        creator.clone(),
+        grant("creator", Role::ProposalCreator),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    for (address, role) in [
+        ("creator", Role::ProposalCreator),
+        ("manager", Role::ConfigManager),
+        ("manager", Role::Guardian),
+    ] {
+        _ = execute(
+            deps.as_mut(),
+            env.clone(),
+            owner.clone(),
+            grant(address, role),
+        )
+        .unwrap();
+        env.block.height += 1;
+    }
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::Roles {
+            address: "manager".to_string(),
+        },
+    )
+    .unwrap();
+    let roles_response: RolesResponse = from_json(&res).unwrap();
+    assert_eq!(
+        vec![Role::ConfigManager, Role::Guardian],
+        roles_response.roles
+    );
+
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::ListRoleMembers {
+            role: Role::ProposalCreator,
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let members_response: RoleMembersResponse = from_json(&res).unwrap();
+    assert_eq!(vec![Addr::unchecked("creator")], members_response.members);
+
+    // 역할별 권한 체크
+    _ = add_propsal(deps.as_mut(), env.clone(), creator.clone()).unwrap();
+    env.block.height += 1;
+    let err = add_propsal(deps.as_mut(), env.clone(), manager.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let update_config = ExecuteMsg::UpdateConfig {
+        max_duration: Some(Duration::Time(100)),
+        max_duration_secondary: None,
+        quorum: None,
+        threshold: None,
+        timelock_seconds: None,
+        nft_address: None,
+        unbonding_period: None,
+        voting_strategy: None,
+        proposal_deposit: None,
+    };
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        creator.clone(),
+        update_config.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    _ = execute(deps.as_mut(), env.clone(), manager.clone(), update_config).unwrap();
+    env.block.height += 1;
+
+    // 역할 회수
+    let msg = ExecuteMsg::RevokeRole {
+        address: "creator".to_string(),
+        role: Role::ProposalCreator,
+    };
+    _ = execute(deps.as_mut(), env.clone(), owner, msg).unwrap();
+    env.block.height += 1;
+    let err = add_propsal(deps.as_mut(), env.clone(), creator).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let res = query(
+        deps.as_ref(),
+        env,
+        QueryMsg::ListRoleMembers {
+            role: Role::ProposalCreator,
+            start_after: None,
+            limit: None,
+        },
+    )
+    .unwrap();
+    let members_response: RoleMembersResponse = from_json(&res).unwrap();
+    assert!(members_response.members.is_empty());
+}
+
+#[test]
+fn test_ownership_transfer() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = mock_info("owner", &[]);
+    let new_owner = mock_info("new-owner", &[]);
+    (_, env) = setup_contract(deps.as_mut(), env.clone(), owner.clone()).unwrap();
+
+    // 새 오너가 수락하기 전까지는 기존 오너 유지
+    let msg = ExecuteMsg::ProposeNewOwner {
+        new_owner: "new-owner".to_string(),
+        expiry: Some(Expiration::AtHeight(env.block.height + 5)),
+    };
+    _ = execute(deps.as_mut(), env.clone(), owner.clone(), msg).unwrap();
+    env.block.height += 1;
+
+    let res = query(deps.as_ref(), env.clone(), QueryMsg::PendingOwner {}).unwrap();
+    let pending_owner_response: PendingOwnerResponse = from_json(&res).unwrap();
+    assert_eq!(
+        Some(Addr::unchecked("new-owner")),
+        pending_owner_response.pending_owner
+    );
+    let err = add_propsal(deps.as_mut(), env.clone(), new_owner.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let accept = ExecuteMsg::AcceptOwnership {};
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        accept.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::NotPendingOwner {}));
+    _ = execute(
+        deps.as_mut(),
+        env.clone(),
+        new_owner.clone(),
+        accept.clone(),
+    )
+    .unwrap();
+    env.block.height += 1;
+
+    let err = add_propsal(deps.as_mut(), env.clone(), owner.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    _ = add_propsal(deps.as_mut(), env.clone(), new_owner.clone()).unwrap();
+    env.block.height += 1;
+
+    // 취소 후에는 수락 불가
+    let msg = ExecuteMsg::ProposeNewOwner {
+        new_owner: "owner".to_string(),
+        expiry: None,
+    };
+    _ = execute(deps.as_mut(), env.clone(), new_owner.clone(), msg).unwrap();
+    env.block.height += 1;
+    let msg = ExecuteMsg::CancelOwnershipTransfer {};
+    _ = execute(deps.as_mut(), env.clone(), new_owner.clone(), msg).unwrap();
+    env.block.height += 1;
+    let err = execute(deps.as_mut(), env.clone(), owner.clone(), accept.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::TransferNotFound {}));
+
+    // 기한이 지나면 수락 불가
+    let msg = ExecuteMsg::ProposeNewOwner {
+        new_owner: "owner".to_string(),
+        expiry: Some(Expiration::AtHeight(env.block.height + 1)),
+    };
+    _ = execute(deps.as_mut(), env.clone(), new_owner.clone(), msg).unwrap();
+    env.block.height += 1;
+    let err = execute(deps.as_mut(), env.clone(), owner, accept).unwrap_err();
+    assert!(matches!(err, ContractError::TransferExpired {}));
+
+    // 소유권 포기 후에는 컨트랙트 자신만 Admin
+    let msg = ExecuteMsg::RenounceOwnership {};
+    _ = execute(deps.as_mut(), env.clone(), new_owner.clone(), msg).unwrap();
+    env.block.height += 1;
+
+    let res = query(deps.as_ref(), env.clone(), QueryMsg::Ownership {}).unwrap();
+    let ownership: Ownership = from_json(&res).unwrap();
+    assert_eq!(None, ownership.owner);
+    assert_eq!(None, ownership.pending_owner);
+
+    let msg = ExecuteMsg::ProposeNewOwner {
+        new_owner: "new-owner".to_string(),
+        expiry: None,
+    };
+    let err = execute(deps.as_mut(), env.clone(), new_owner.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::NoOwner {}));
+    let err = add_propsal(deps.as_mut(), env.clone(), new_owner).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let contract = mock_info(env.contract.address.as_str(), &[]);
+    _ = add_propsal(deps.as_mut(), env, contract).unwrap();
+}
+
+#[test]
+fn test_cw20_deposit() {
+    #[cw_serde]
+    enum Cw20ExecuteMsg {
+        Transfer {
+            recipient: String,
+            amount: Uint128,
+        },
+        TransferFrom {
+            owner: String,
+            recipient: String,
+            amount: Uint128,
+        },
+    }
+
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = mock_info("owner", &[]);
+    let holder = mock_info("holder", &[]);
+    mock_nft_owners(&mut deps, vec![("1", "holder")]);
+    (_, env) = setup_contract(deps.as_mut(), env.clone(), owner.clone()).unwrap();
+
+    let msg = ExecuteMsg::UpdateConfig {
+        max_duration: None,
+        max_duration_secondary: None,
+        quorum: None,
+        threshold: None,
+        timelock_seconds: None,
+        nft_address: Some("nft".to_string()),
+        unbonding_period: None,
+        voting_strategy: None,
+        proposal_deposit: Some(ProposalDeposit {
+            denom: DepositDenom::Cw20 {
+                address: Addr::unchecked("cw20"),
+            },
+            amount: Uint128::new(100),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+        }),
+    };
+    _ = execute(deps.as_mut(), env.clone(), owner.clone(), msg).unwrap();
+    env.block.height += 1;
+
+    // cw20 보증금은 TransferFrom 으로 수령
+    let res = add_propsal(deps.as_mut(), env.clone(), holder).unwrap();
+    env.block.height += 1;
+    assert_eq!(
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: "cw20".to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::TransferFrom {
+                owner: "holder".to_string(),
+                recipient: env.contract.address.to_string(),
+                amount: Uint128::new(100),
+            })
+            .unwrap(),
+            funds: vec![],
+        })],
+        res.messages
+    );
+
+    let propsal = PROPSALS.load(&deps.storage, 1).unwrap();
+    assert_eq!(Addr::unchecked("holder"), propsal.proposer);
+    assert_eq!(DepositStatus::Held, propsal.deposit.unwrap().status);
+
+    // 삭제된 제안의 보증금은 반환
+    let msg = ExecuteMsg::RemovePropsal { id: 1 };
+    let res = execute(deps.as_mut(), env, owner, msg).unwrap();
+    assert_eq!(
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: "cw20".to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: "holder".to_string(),
+                amount: Uint128::new(100),
+            })
+            .unwrap(),
+            funds: vec![],
+        })],
+        res.messages
+    );
+}
+
+#[test]
+fn test_voting_starts_at() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    mock_nft_owners(&mut deps, vec![("1", owner)]);
+    (_, env) = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+
+    let add_msg = |voting_starts_at: Scheduled| ExecuteMsg::AddPropsal {
+        title: String::from("pending-propsal"),
+        status: PropsalStatus::Enabled,
+        nft_address: String::from("nft"),
+        expiration: Expiration::AtTime(env.block.time.plus_seconds(100)),
+        candidates: vec![Candidate {
+            id: 1,
+            name: ("candiate1").to_string(),
+            msgs: vec![],
+        }],
+        allow_revoting: false,
+        tie_breaker: None,
+        voting_starts_at: Some(voting_starts_at),
+        quorum: None,
+        threshold: None,
+        power_source: None,
+        voting_strategy: None,
+        seats: None,
+    };
+
+    // 투표 시작은 마감기한 이전이어야 함
+    let msg = add_msg(Scheduled::AtTime(env.block.time.plus_seconds(100)));
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidVotingStart {}));
+
+    // 마감기한과 단위가 다르면 비교할 수 없으므로 불가
+    let msg = add_msg(Scheduled::AtHeight(env.block.height + 5));
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::ExpirationUnitMismatch {}));
+
+    let msg = add_msg(Scheduled::AtTime(env.block.time.plus_seconds(50)));
+    _ = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    assert_eq!(
+        PropsalStatus::Pending,
+        PROPSALS.load(&deps.storage, 1).unwrap().status
+    );
+
+    let propsal_status = |deps: Deps, env: Env| -> PropsalStatus {
+        let res = query(deps, env, QueryMsg::Propsal { id: 1 }).unwrap();
+        let propsal_response: PropsalResponse = from_json(&res).unwrap();
+        propsal_response.propsal.status
+    };
+
+    env.block.height += 1;
+    let err = execute_vote(deps.as_mut(), env.clone(), info.clone(), 1, 1, &["1"]).unwrap_err();
+    assert!(matches!(err, ContractError::VotingNotStarted {}));
+
+    // 시작 시간이 지나면 저장된 상태와 관계없이 투표 가능
+    env.block.height += 1;
+    env.block.time = env.block.time.plus_seconds(50);
+    assert_eq!(
+        PropsalStatus::Enabled,
+        propsal_status(deps.as_ref(), env.clone())
+    );
+    _ = execute_vote(deps.as_mut(), env.clone(), info, 1, 1, &["1"]).unwrap();
+
+    env.block.time = env.block.time.plus_seconds(50);
+    assert_eq!(
+        PropsalStatus::Expired,
+        propsal_status(deps.as_ref(), env.clone())
+    );
+    assert_eq!(
+        PropsalStatus::Pending,
+        PROPSALS.load(&deps.storage, 1).unwrap().status
+    );
+
+    // 확정 전 투표 결과도 현재 상태의 제안으로 계산
+    let res = query(deps.as_ref(), env, QueryMsg::PropsalResult { id: 1 }).unwrap();
+    let propsal_result = from_json::<PropsalResultResponse>(&res)
+        .unwrap()
+        .propsal_result;
+    assert_eq!(PropsalStatus::Expired, propsal_result.propsal.status);
+}
+
+#[test]
+fn test_block_height_expiration() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = "owner";
+    let info = mock_info(owner, &[]);
+    mock_nft_owners(&mut deps, vec![("1", owner)]);
+    (_, env) = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+
+    let add_msg = |expiration: Expiration| ExecuteMsg::AddPropsal {
+        title: String::from("height-propsal"),
+        status: PropsalStatus::Enabled,
+        nft_address: String::from("nft"),
+        expiration,
+        candidates: vec![Candidate {
+            id: 1,
+            name: ("candiate1").to_string(),
+            msgs: vec![],
+        }],
+        allow_revoting: false,
+        tie_breaker: None,
+        voting_starts_at: None,
+        quorum: None,
+        threshold: None,
+        power_source: None,
+        voting_strategy: None,
+        seats: None,
+    };
+
+    let msg = add_msg(Expiration::Never {});
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidExpiration {}));
+
+    // 블록 높이 최대 등록 기간이 없으면 블록 높이 마감기한 불가
+    let msg = add_msg(Expiration::AtHeight(env.block.height + 5));
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::ExpirationUnitMismatch {}));
+
+    // 보조 최대 등록 기간은 max_duration 과 다른 단위
+    let update_secondary = |max_duration_secondary: Duration| ExecuteMsg::UpdateConfig {
+        max_duration: None,
+        max_duration_secondary: Some(max_duration_secondary),
+        quorum: None,
+        threshold: None,
+        timelock_seconds: None,
+        nft_address: None,
+        unbonding_period: None,
+        voting_strategy: None,
+        proposal_deposit: None,
+    };
+    let msg = update_secondary(Duration::Time(10));
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::ExpirationUnitMismatch {}));
+
+    let msg = ExecuteMsg::UpdateConfig {
+        max_duration: None,
+        max_duration_secondary: Some(Duration::Height(10)),
+        quorum: None,
+        threshold: None,
+        timelock_seconds: None,
+        nft_address: None,
+        unbonding_period: None,
+        voting_strategy: None,
+        proposal_deposit: None,
+    };
+    _ = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    env.block.height += 1;
+
+    let msg = add_msg(Expiration::AtHeight(env.block.height + 20));
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::MaxDuration {}));
+    let msg = add_msg(Expiration::AtHeight(env.block.height - 1));
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::LowerTime {}));
+
+    let msg = add_msg(Expiration::AtHeight(env.block.height + 5));
+    _ = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    // 시간 마감기한도 계속 사용 가능, 투표 시작과 마감기한은 같은 단위
+    let msg = add_msg(Expiration::AtTime(env.block.time.plus_seconds(100)));
+    _ = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+    let mut msg = add_msg(Expiration::AtHeight(env.block.height + 5));
+    if let ExecuteMsg::AddPropsal {
+        voting_starts_at, ..
+    } = &mut msg
+    {
+        *voting_starts_at = Some(Scheduled::AtTime(env.block.time.plus_seconds(10)));
+    }
+    let err = execute(deps.as_mut(), env.clone(), info.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::ExpirationUnitMismatch {}));
+
+    // 블록 높이 기준으로 마감
+    env.block.height += 5;
+    let err = execute_vote(deps.as_mut(), env.clone(), info.clone(), 1, 1, &["1"]).unwrap_err();
+    assert!(matches!(err, ContractError::VotingClosed {}));
+    let msg = ExecuteMsg::Finalize { id: 1 };
+    _ = execute(deps.as_mut(), env, info, msg).unwrap();
+}
+
+#[test]
+fn test_quadratic_vote() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("owner", &[]);
+    let holder = mock_info("holder", &[]);
+    let token_ids = ["1", "2", "3", "4", "5"];
+    mock_nft_owners(
+        &mut deps,
+        token_ids
+            .iter()
+            .map(|token_id| (*token_id, "holder"))
+            .collect(),
+    );
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    for _ in 0..2 {
+        _ = add_strategy_propsal(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            VotingStrategy::Quadratic,
+            2,
+            1,
+        )
+        .unwrap();
+    }
+    let quadratic_vote =
+        |propsal_id: u64, allocations: Vec<(u64, u64)>| ExecuteMsg::ExecuteQuadraticVote {
+            propsal_id,
+            allocations: allocations
+                .into_iter()
+                .map(|(candidate_id, credits)| CreditAllocation {
+                    candidate_id,
+                    credits,
+                })
+                .collect(),
+            token_ids: token_ids
+                .iter()
+                .map(|token_id| token_id.to_string())
+                .collect(),
+        };
+
+    // 단일 후보 투표, 중복 후보, power 를 넘거나 합이 넘치는 credit 은 거부
+    let err = execute_vote(deps.as_mut(), env.clone(), holder.clone(), 2, 1, &["1"]).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidBallot {}));
+    let msg = quadratic_vote(2, vec![(1, 1), (1, 1)]);
+    let err = execute(deps.as_mut(), env.clone(), holder.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidBallot {}));
+    let msg = quadratic_vote(2, vec![(1, u64::MAX), (2, 2)]);
+    let err = execute(deps.as_mut(), env.clone(), holder.clone(), msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidBallot {}));
+    let msg = quadratic_vote(2, vec![(1, 4), (2, 2)]);
+    let err = execute(deps.as_mut(), env.clone(), holder.clone(), msg).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::InsufficientCredits {
+            spent: 6,
+            available: 5
+        }
+    ));
+
+    let msg = quadratic_vote(1, vec![(1, 4), (2, 1)]);
+    _ = execute(deps.as_mut(), env.clone(), holder, msg).unwrap();
+
+    let res = query(deps.as_ref(), env, QueryMsg::PropsalResult { id: 1 }).unwrap();
+    let propsal_result = from_json::<PropsalResultResponse>(&res)
+        .unwrap()
+        .propsal_result;
+    assert_eq!(
+        vec![(4, Decimal::from_ratio(2u64, 1u64)), (1, Decimal::one())],
+        propsal_result
+            .tallies
+            .iter()
+            .map(|tally| (tally.power, tally.weight))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Some(1),
+        propsal_result.winners.first().map(|winner| winner.id)
+    );
+    assert_eq!(5, propsal_result.turnout.total_power);
+}
+
+#[test]
+fn test_ranked_choice() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("owner", &[]);
+    mock_nft_owners(
+        &mut deps,
+        vec![
+            ("1", "a"),
+            ("2", "a"),
+            ("3", "a"),
+            ("4", "b"),
+            ("5", "b"),
+            ("6", "c"),
+            ("7", "c"),
+        ],
+    );
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    _ = add_strategy_propsal(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        VotingStrategy::RankedChoice,
+        3,
+        1,
+    )
+    .unwrap();
+    let ranked_vote = |ranking: Vec<u64>, token_ids: &[&str]| ExecuteMsg::ExecuteRankedVote {
+        propsal_id: 1,
+        ranking,
+        token_ids: token_ids
+            .iter()
+            .map(|token_id| token_id.to_string())
+            .collect(),
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("a", &[]),
+        ranked_vote(vec![1, 4], &["1"]),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::InvalidCandidate { candidate_id: 4 }
+    ));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("a", &[]),
+        ranked_vote(vec![], &["1"]),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidBallot {}));
+
+    let ballots = vec![
+        ("a", vec![1], vec!["1", "2", "3"]),
+        ("b", vec![2], vec!["4", "5"]),
+        ("c", vec![3, 2], vec!["6", "7"]),
+    ];
+    for (voter, ranking, token_ids) in ballots {
+        _ = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(voter, &[]),
+            ranked_vote(ranking, &token_ids),
+        )
+        .unwrap();
+    }
+
+    // 1라운드 최다 득표자 1 은 과반이 아니므로 동점 최저 득표 후보 중 3 탈락 후 2 가 과반
+    let res = query(deps.as_ref(), env, QueryMsg::PropsalResult { id: 1 }).unwrap();
+    let propsal_result = from_json::<PropsalResultResponse>(&res)
+        .unwrap()
+        .propsal_result;
+    assert_eq!(
+        vec![
+            RunoffRound {
+                round: 1,
+                counts: vec![
+                    RoundCount {
+                        candidate_id: 1,
+                        power: Decimal::from_ratio(3u64, 1u64)
+                    },
+                    RoundCount {
+                        candidate_id: 2,
+                        power: Decimal::from_ratio(2u64, 1u64)
+                    },
+                    RoundCount {
+                        candidate_id: 3,
+                        power: Decimal::from_ratio(2u64, 1u64)
+                    },
+                ],
+                elected: vec![],
+                eliminated: Some(3),
+                transfers: vec![VoteTransfer {
+                    from: 3,
+                    to: Some(2),
+                    power: Decimal::from_ratio(2u64, 1u64)
+                }],
+                exhausted: Decimal::zero(),
+            },
+            RunoffRound {
+                round: 2,
+                counts: vec![
+                    RoundCount {
+                        candidate_id: 1,
+                        power: Decimal::from_ratio(3u64, 1u64)
+                    },
+                    RoundCount {
+                        candidate_id: 2,
+                        power: Decimal::from_ratio(4u64, 1u64)
+                    },
+                ],
+                elected: vec![2],
+                eliminated: None,
+                transfers: vec![],
+                exhausted: Decimal::zero(),
+            },
+        ],
+        propsal_result.rounds
+    );
+    assert_eq!(
+        Some(2),
+        propsal_result.winners.first().map(|winner| winner.id)
+    );
+    assert_eq!(PropsalResultStatus::Passed, propsal_result.status);
+    // 후보별 득표는 1순위 기준
+    assert_eq!(
+        vec![3, 2, 2],
+        propsal_result
+            .tallies
+            .iter()
+            .map(|tally| tally.power)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_approval_vote() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("owner", &[]);
+    mock_nft_owners(&mut deps, vec![("1", "a"), ("2", "a"), ("3", "b")]);
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    _ = add_strategy_propsal(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        VotingStrategy::Approval {
+            max_choices: Some(2),
+        },
+        3,
+        1,
+    )
+    .unwrap();
+    let approval_vote =
+        |candidate_ids: Vec<u64>, token_ids: &[&str]| ExecuteMsg::ExecuteApprovalVote {
+            propsal_id: 1,
+            candidate_ids,
+            token_ids: token_ids
+                .iter()
+                .map(|token_id| token_id.to_string())
+                .collect(),
+        };
+
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("a", &[]),
+        approval_vote(vec![1, 2, 3], &["1", "2"]),
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::TooManyChoices { max_choices: 2 }
+    ));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("a", &[]),
+        approval_vote(vec![1, 1], &["1", "2"]),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidBallot {}));
+
+    _ = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("a", &[]),
+        approval_vote(vec![1, 2], &["1", "2"]),
+    )
+    .unwrap();
+    _ = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("b", &[]),
+        approval_vote(vec![2], &["3"]),
+    )
+    .unwrap();
+
+    // 승인한 후보마다 투표자의 power 전체가 득표
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::PropsalResult { id: 1 },
+    )
+    .unwrap();
+    let propsal_result = from_json::<PropsalResultResponse>(&res)
+        .unwrap()
+        .propsal_result;
+    assert_eq!(
+        vec![2, 3, 0],
+        propsal_result
+            .tallies
+            .iter()
+            .map(|tally| tally.power)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Some(2),
+        propsal_result.winners.first().map(|winner| winner.id)
+    );
+    assert_eq!(3, propsal_result.turnout.total_power);
+
+    // 승인한 모든 후보의 투표 목록에 포함
+    let candidate_voters = |candidate_id: u64| -> Vec<Addr> {
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::CandidateVotes {
+                propsal_id: 1,
+                candidate_id,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+        from_json::<VotesResponse>(&res)
+            .unwrap()
+            .votes
+            .into_iter()
+            .map(|vote| vote.voter)
+            .collect()
+    };
+    assert_eq!(vec![Addr::unchecked("a")], candidate_voters(1));
+    assert_eq!(
+        vec![Addr::unchecked("a"), Addr::unchecked("b")],
+        candidate_voters(2)
+    );
+    assert!(candidate_voters(3).is_empty());
+}
+
+#[test]
+fn test_multi_winner_plurality() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("owner", &[]);
+    mock_nft_owners(
+        &mut deps,
+        vec![("1", "a"), ("2", "a"), ("3", "a"), ("4", "b"), ("5", "c")],
+    );
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+
+    // 당선자 수는 1 이상 후보 수 이하, 순위 투표는 한 명만 선출
+    for (voting_strategy, seats) in [
+        (VotingStrategy::Plurality, 0),
+        (VotingStrategy::Plurality, 4),
+        (VotingStrategy::RankedChoice, 2),
+    ] {
+        let err = add_strategy_propsal(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            voting_strategy,
+            3,
+            seats,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSeats { seats: s } if s == seats));
+    }
+
+    _ = add_strategy_propsal(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        VotingStrategy::Plurality,
+        3,
+        2,
+    )
+    .unwrap();
+    for (voter, candidate_id, token_ids) in [
+        ("a", 1, &["1", "2", "3"][..]),
+        ("b", 2, &["4"][..]),
+        ("c", 3, &["5"][..]),
+    ] {
+        _ = execute_vote(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(voter, &[]),
+            1,
+            candidate_id,
+            token_ids,
+        )
+        .unwrap();
+    }
+
+    // 두 번째 자리가 동점이면 첫 번째 당선자만 결정
+    let res = query(deps.as_ref(), env, QueryMsg::PropsalResult { id: 1 }).unwrap();
+    let propsal_result = from_json::<PropsalResultResponse>(&res)
+        .unwrap()
+        .propsal_result;
+    assert_eq!(
+        PropsalOutcome::Tie {
+            candidate_ids: vec![2, 3]
+        },
+        propsal_result.outcome
+    );
+    assert_eq!(
+        vec![1],
+        propsal_result
+            .winners
+            .iter()
+            .map(|winner| winner.id)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(PropsalResultStatus::Rejected, propsal_result.status);
+}
+
+#[test]
+fn test_single_transferable_vote() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("owner", &[]);
+    mock_nft_owners(
+        &mut deps,
+        vec![
+            ("1", "a"),
+            ("2", "a"),
+            ("3", "a"),
+            ("4", "a"),
+            ("5", "a"),
+            ("6", "b"),
+            ("7", "b"),
+            ("8", "c"),
+            ("9", "c"),
+        ],
+    );
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    _ = add_strategy_propsal(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        VotingStrategy::SingleTransferableVote,
+        3,
+        2,
+    )
+    .unwrap();
+    let ballots = vec![
+        ("a", vec![1, 2], vec!["1", "2", "3", "4", "5"]),
+        ("b", vec![2], vec!["6", "7"]),
+        ("c", vec![3], vec!["8", "9"]),
+    ];
+    for (voter, ranking, token_ids) in ballots {
+        _ = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(voter, &[]),
+            ExecuteMsg::ExecuteRankedVote {
+                propsal_id: 1,
+                ranking,
+                token_ids: token_ids.iter().map(|id| id.to_string()).collect(),
+            },
+        )
+        .unwrap();
+    }
+
+    // quota = floor(9 / 3) + 1 = 4, 1 당선 후 잉여표 1 이 2 로 이양,
+    // 3 탈락 후 남은 후보 2 가 마지막 자리에 당선
+    let res = query(deps.as_ref(), env, QueryMsg::PropsalResult { id: 1 }).unwrap();
+    let propsal_result = from_json::<PropsalResultResponse>(&res)
+        .unwrap()
+        .propsal_result;
+    let power = |power: u64| Decimal::from_ratio(power, 1u64);
+    let count = |candidate_id: u64, p: u64| RoundCount {
+        candidate_id,
+        power: power(p),
+    };
+    assert_eq!(Some(power(4)), propsal_result.quota);
+    assert_eq!(
+        vec![
+            RunoffRound {
+                round: 1,
+                counts: vec![count(1, 5), count(2, 2), count(3, 2)],
+                elected: vec![1],
+                eliminated: None,
+                transfers: vec![VoteTransfer {
+                    from: 1,
+                    to: Some(2),
+                    power: power(1),
+                }],
+                exhausted: Decimal::zero(),
+            },
+            RunoffRound {
+                round: 2,
+                counts: vec![count(2, 3), count(3, 2)],
+                elected: vec![],
+                eliminated: Some(3),
+                transfers: vec![VoteTransfer {
+                    from: 3,
+                    to: None,
+                    power: power(2),
+                }],
+                exhausted: Decimal::zero(),
+            },
+            RunoffRound {
+                round: 3,
+                counts: vec![count(2, 3)],
+                elected: vec![2],
+                eliminated: None,
+                transfers: vec![],
+                exhausted: power(2),
+            },
+        ],
+        propsal_result.rounds
+    );
+    assert_eq!(PropsalOutcome::Winner {}, propsal_result.outcome);
+    assert_eq!(
+        vec![1, 2],
+        propsal_result
+            .winners
+            .iter()
+            .map(|winner| winner.id)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(PropsalResultStatus::Passed, propsal_result.status);
+}
+
+#[test]
+fn test_single_choice_vote() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("owner", &[]);
+    mock_nft_owners(
+        &mut deps,
+        vec![
+            ("1", "a"),
+            ("2", "a"),
+            ("3", "a"),
+            ("4", "b"),
+            ("5", "c"),
+            ("6", "c"),
+            ("7", "d"),
+        ],
+    );
+    _ = setup_contract(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    let single_choice = VotingStrategy::SingleChoice {
+        veto_threshold: None,
+    };
+    let err = add_strategy_propsal(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        single_choice.clone(),
+        0,
+        2,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidSeats { seats: 2 }));
+    _ = add_strategy_propsal(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        single_choice.clone(),
+        0,
+        1,
+    )
+    .unwrap();
+
+    let ballots = vec![
+        ("a", VoteOption::Yes, vec!["1", "2", "3"]),
+        ("b", VoteOption::No, vec!["4"]),
+        ("c", VoteOption::Abstain, vec!["5", "6"]),
+        ("d", VoteOption::NoWithVeto, vec!["7"]),
+    ];
+    for (voter, option, token_ids) in ballots {
+        _ = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(voter, &[]),
+            ExecuteMsg::ExecuteSingleChoiceVote {
+                propsal_id: 1,
+                option,
+                token_ids: token_ids.iter().map(|id| id.to_string()).collect(),
+            },
+        )
+        .unwrap();
+    }
+
+    // 기권은 정족수에만 포함, Yes 3 / (Yes 3 + No 1 + NoWithVeto 1) 이 과반
+    // NoWithVeto 1 / 7 은 기본 거부 기준 0.334 이하
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::PropsalResult { id: 1 },
+    )
+    .unwrap();
+    let propsal_result = from_json::<PropsalResultResponse>(&res)
+        .unwrap()
+        .propsal_result;
+    assert_eq!(
+        vec![
+            ("Yes".to_string(), 3),
+            ("No".to_string(), 1),
+            ("Abstain".to_string(), 2),
+            ("NoWithVeto".to_string(), 1),
+        ],
+        propsal_result
+            .tallies
+            .iter()
+            .map(|tally| (tally.name.clone(), tally.power))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![VoteOption::Yes.candidate_id()],
+        propsal_result
+            .winners
+            .iter()
+            .map(|winner| winner.id)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(PropsalResultStatus::Passed, propsal_result.status);
+    assert_eq!(7, propsal_result.turnout.total_power);
+
+    // 부결되면 당선자 없음
+    _ = add_strategy_propsal(deps.as_mut(), env.clone(), info, single_choice, 0, 1).unwrap();
+    _ = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("a", &[]),
+        ExecuteMsg::ExecuteSingleChoiceVote {
+            propsal_id: 2,
+            option: VoteOption::No,
+            token_ids: vec!["1".to_string()],
+        },
+    )
+    .unwrap();
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::PropsalResult { id: 2 },
+    )
+    .unwrap();
+    let propsal_result = from_json::<PropsalResultResponse>(&res)
+        .unwrap()
+        .propsal_result;
+    assert_eq!(PropsalResultStatus::Rejected, propsal_result.status);
+    assert!(propsal_result.winners.is_empty());
+
+    // 찬반 투표 용지는 찬반 투표 제안에만 사용
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("b", &[]),
+        ExecuteMsg::ExecuteVote {
+            propsal_id: 2,
+            candidate_id: VoteOption::Yes.candidate_id(),
+            token_ids: vec!["4".to_string()],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidBallot {}));
+    _ = add_strategy_propsal(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        VotingStrategy::Plurality,
+        4,
+        1,
+    )
+    .unwrap();
+    let err = execute(
+        deps.as_mut(),
+        env,
+        mock_info("b", &[]),
+        ExecuteMsg::ExecuteSingleChoiceVote {
+            propsal_id: 3,
+            option: VoteOption::Yes,
+            token_ids: vec!["4".to_string()],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidBallot {}));
+}
+
+fn add_propsal(deps: DepsMut, mut env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let title = String::from("test-propsal");
+    let status = PropsalStatus::Enabled;
+    let nft_address = String::from("nft");
+    let expiration = Expiration::AtTime(env.block.time.plus_seconds(100));
+    let candidates = vec![Candidate {
+        id: 1,
+        name: ("candiate1").to_string(),
+        msgs: vec![],
+    }];
+
+    let msg = ExecuteMsg::AddPropsal {
+        title: title.clone(),
+        status: status.clone(),
+        nft_address: nft_address.clone(),
+        expiration,
+        candidates: candidates.clone(),
+        allow_revoting: false,
+        tie_breaker: None,
+        voting_starts_at: None,
+        quorum: None,
+        threshold: None,
+        power_source: None,
+        voting_strategy: None,
+        seats: None,
+    };
+
+    let res = execute(deps, env.clone(), info.clone(), msg.clone())?;
+
+    env.block.height += 1;
+
+    Ok(res)
+}
+
+/// 후보 1..=candidate_count 를 가진 집계 방식별 제안
+fn add_strategy_propsal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    voting_strategy: VotingStrategy,
+    candidate_count: u64,
+    seats: u32,
+) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::AddPropsal {
+        title: String::from("strategy-propsal"),
+        status: PropsalStatus::Enabled,
+        nft_address: String::from("nft"),
+        expiration: Expiration::AtTime(env.block.time.plus_seconds(100)),
+        candidates: (1..=candidate_count)
+            .map(|id| Candidate {
+                id,
+                name: format!("candidate{}", id),
+                msgs: vec![],
+            })
+            .collect(),
+        allow_revoting: false,
+        tie_breaker: None,
+        voting_starts_at: None,
+        quorum: None,
+        threshold: None,
+        power_source: None,
+        voting_strategy: Some(voting_strategy),
+        seats: Some(seats),
+    };
+
+    execute(deps, env, info, msg)
+}
+
+fn execute_vote(
+    deps: DepsMut,
+    mut env: Env,
+    info: MessageInfo,
+    propsal_id: u64,
+    candidate_id: u64,
+    token_ids: &[&str],
+) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::ExecuteVote {
+        propsal_id,
+        candidate_id,
+        token_ids: token_ids
+            .iter()
+            .map(|token_id| token_id.to_string())
+            .collect(),
+    };
+
+    let res = execute(deps, env.clone(), info.clone(), msg.clone())?;
+
+    env.block.height += 1;
+
+    Ok(res)
+}
+
+/// cw721 컨트랙트 쿼리 mock (token_id, owner)
+fn mock_nft_owners(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>,
+    owners: Vec<(&str, &str)>,
+) {
+    let owners: Vec<(String, String)> = owners
+        .into_iter()
+        .map(|(token_id, owner)| (token_id.to_string(), owner.to_string()))
+        .collect();
+
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { msg, .. } => match from_json(msg) {
+            Ok(Cw721QueryMsg::NumTokens {}) => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&NumTokensResponse {
+                    count: owners.len() as u64,
+                })
+                .unwrap(),
+            )),
+            Ok(Cw721QueryMsg::Tokens { owner, .. }) => SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&TokensResponse {
+                    tokens: owners
+                        .iter()
+                        .filter(|(_, o)| *o == owner)
+                        .map(|(t, _)| t.clone())
+                        .collect(),
+                })
+                .unwrap(),
+            )),
+            Ok(Cw721QueryMsg::OwnerOf { token_id, .. }) => {
+                match owners.iter().find(|(t, _)| *t == token_id) {
+                    Some((_, owner)) => SystemResult::Ok(ContractResult::Ok(
+                        to_json_binary(&OwnerOfResponse {
+                            owner: owner.clone(),
+                            approvals: vec![],
+                        })
+                        .unwrap(),
+                    )),
+                    None => SystemResult::Ok(ContractResult::Err("token not found".to_string())),
+                }
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "cw721".to_string(),
+            }),
+        },
+        _ => SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: "wasm".to_string(),
+        }),
+    });
 }
@@ -0,0 +1,146 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Env, MessageInfo, StdResult, Uint128, WasmMsg,
+};
+use cw_utils::{must_pay, nonpayable};
+
+use crate::error::ContractError;
+use crate::state::{
+    Deposit, DepositDenom, DepositRefundPolicy, DepositStatus, ProposalDeposit, PropsalResultStatus,
+};
+
+/// cw20 실행 메시지 (보증금 처리에 필요한 것만)
+#[cw_serde]
+enum Cw20ExecuteMsg {
+    Transfer {
+        recipient: String,
+        amount: Uint128,
+    },
+    Burn {
+        amount: Uint128,
+    },
+    TransferFrom {
+        owner: String,
+        recipient: String,
+        amount: Uint128,
+    },
+}
+
+/// 제안 등록 시 보증금 수령, cw20 은 컨트랙트로 가져오는 메시지 반환
+pub fn take_deposit(
+    env: &Env,
+    info: &MessageInfo,
+    proposal_deposit: &ProposalDeposit,
+) -> Result<(Deposit, Vec<CosmosMsg>), ContractError> {
+    let amount = proposal_deposit.amount;
+    let msgs = match &proposal_deposit.denom {
+        DepositDenom::Native { denom } => {
+            if must_pay(info, denom)? != amount {
+                return Err(ContractError::InvalidDeposit {
+                    amount: format!("{}{}", amount, denom),
+                });
+            }
+            vec![]
+        }
+        DepositDenom::Cw20 { address } => {
+            nonpayable(info)?;
+            vec![WasmMsg::Execute {
+                contract_addr: address.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::TransferFrom {
+                    owner: info.sender.to_string(),
+                    recipient: env.contract.address.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }
+            .into()]
+        }
+    };
+
+    let deposit = Deposit {
+        denom: proposal_deposit.denom.clone(),
+        amount,
+        refund_policy: proposal_deposit.refund_policy.clone(),
+        status: DepositStatus::Held,
+    };
+
+    Ok((deposit, msgs))
+}
+
+/// 결과에 따른 보증금 반환 여부
+pub fn should_refund(refund_policy: &DepositRefundPolicy, status: &PropsalResultStatus) -> bool {
+    match refund_policy {
+        DepositRefundPolicy::Always => true,
+        DepositRefundPolicy::QuorumMet => *status != PropsalResultStatus::QuorumNotMet,
+        DepositRefundPolicy::OnlyPassed => *status == PropsalResultStatus::Passed,
+    }
+}
+
+/// 보관 중인 보증금 반환 또는 귀속, 반환 시 전송 메시지 반환
+pub fn settle_deposit(
+    deposit: &mut Option<Deposit>,
+    proposer: &Addr,
+    refund: bool,
+) -> StdResult<Vec<CosmosMsg>> {
+    let deposit = match deposit {
+        Some(deposit) if deposit.status == DepositStatus::Held => deposit,
+        _ => return Ok(vec![]),
+    };
+    if !refund {
+        // 컨트랙트에 남겨 DAO 에 귀속
+        deposit.status = DepositStatus::Forfeited;
+        return Ok(vec![]);
+    }
+
+    deposit.status = DepositStatus::Refunded;
+    let msg = match &deposit.denom {
+        DepositDenom::Native { denom } => BankMsg::Send {
+            to_address: proposer.to_string(),
+            amount: vec![Coin {
+                denom: denom.clone(),
+                amount: deposit.amount,
+            }],
+        }
+        .into(),
+        DepositDenom::Cw20 { address } => WasmMsg::Execute {
+            contract_addr: address.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: proposer.to_string(),
+                amount: deposit.amount,
+            })?,
+            funds: vec![],
+        }
+        .into(),
+    };
+
+    Ok(vec![msg])
+}
+
+/// 보관 중인 보증금 소각, 소각 메시지 반환
+pub fn burn_deposit(deposit: &mut Option<Deposit>) -> StdResult<Vec<CosmosMsg>> {
+    let deposit = match deposit {
+        Some(deposit) if deposit.status == DepositStatus::Held => deposit,
+        _ => return Ok(vec![]),
+    };
+
+    deposit.status = DepositStatus::Burned;
+    let msg = match &deposit.denom {
+        DepositDenom::Native { denom } => BankMsg::Burn {
+            amount: vec![Coin {
+                denom: denom.clone(),
+                amount: deposit.amount,
+            }],
+        }
+        .into(),
+        DepositDenom::Cw20 { address } => WasmMsg::Execute {
+            contract_addr: address.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Burn {
+                amount: deposit.amount,
+            })?,
+            funds: vec![],
+        }
+        .into(),
+    };
+
+    Ok(vec![msg])
+}
@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use cw_utils::PaymentError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,9 +7,24 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Contract ownership has been renounced")]
+    NoOwner {},
+
+    #[error("Sender is not the pending owner")]
+    NotPendingOwner {},
+
+    #[error("There is no pending ownership transfer")]
+    TransferNotFound {},
+
+    #[error("Ownership transfer has expired")]
+    TransferExpired {},
+
     #[error("Invalid Contract Name")]
     InvalidContractName {},
 
@@ -18,9 +34,108 @@ pub enum ContractError {
     #[error("Exceed max duration")]
     MaxDuration {},
 
+    #[error("Expiration must be a block height or a time")]
+    InvalidExpiration {},
+
+    #[error("Expiration unit does not match the max duration or the voting start")]
+    ExpirationUnitMismatch {},
+
     #[error("It is lower than the block time")]
     LowerTime {},
 
     #[error("Propsal Status is Disabled.")]
     StatusDisabled {},
+
+    #[error("Sender is not the owner of token {token_id}")]
+    NotTokenOwner { token_id: String },
+
+    #[error("No voting power: every token has already voted on this propsal")]
+    NoVotingPower {},
+
+    #[error("Already voted on this propsal")]
+    AlreadyVoted {},
+
+    #[error("Voting is closed for this propsal")]
+    VotingClosed {},
+
+    #[error("Candidate {candidate_id} does not exist in this propsal")]
+    InvalidCandidate { candidate_id: u64 },
+
+    #[error("Voting has not ended yet")]
+    VotingNotEnded {},
+
+    #[error("Voting has not started yet")]
+    VotingNotStarted {},
+
+    #[error("Voting must start before the expiration")]
+    InvalidVotingStart {},
+
+    #[error("Propsal is already finalized")]
+    PropsalFinalized {},
+
+    #[error("Invalid propsal status: {status}")]
+    InvalidStatus { status: String },
+
+    #[error("Percentage must be between 0 and 1")]
+    InvalidPercentage {},
+
+    #[error("Propsal has not passed")]
+    PropsalNotPassed {},
+
+    #[error("Propsal is already executed")]
+    AlreadyExecuted {},
+
+    #[error("Propsal has no messages to execute")]
+    NothingToExecute {},
+
+    #[error("Propsal is not queued")]
+    PropsalNotQueued {},
+
+    #[error("Propsal is timelocked until {executable_at}")]
+    Timelocked { executable_at: u64 },
+
+    #[error("Candidates and nft address cannot change after votes are cast")]
+    PropsalLocked {},
+
+    #[error("Propsal is cancelled")]
+    PropsalCancelled {},
+
+    #[error("Sender does not hold any token of the governing nft")]
+    NotNftHolder {},
+
+    #[error("Only Admin or ProposalCreator can override voting rules")]
+    VotingRulesOverride {},
+
+    #[error("Only the governing nft can be staked")]
+    InvalidStakingNft {},
+
+    #[error("Cannot change the governing nft while nfts are staked or unbonding")]
+    NftStaked {},
+
+    #[error("Token {token_id} is not staked by the sender")]
+    NotStaked { token_id: String },
+
+    #[error("No unbonded nft to claim")]
+    NothingToClaim {},
+
+    #[error("Cannot delegate to self")]
+    SelfDelegation {},
+
+    #[error("Sender has not delegated")]
+    NotDelegating {},
+
+    #[error("Ballot does not match the propsal voting strategy or candidates")]
+    InvalidBallot {},
+
+    #[error("Too many choices: at most {max_choices} candidates can be approved")]
+    TooManyChoices { max_choices: u32 },
+
+    #[error("Invalid seats: {seats}")]
+    InvalidSeats { seats: u32 },
+
+    #[error("Insufficient credits: {spent} spent, {available} available")]
+    InsufficientCredits { spent: u64, available: u64 },
+
+    #[error("Invalid deposit: {amount} is required")]
+    InvalidDeposit { amount: String },
 }
@@ -1,15 +1,33 @@
+use std::cmp::Ordering;
+
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    to_json_binary, Addr, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, Event, MessageInfo, Order,
+    Reply, Response, StdResult, Storage, SubMsg, SubMsgResult, WasmMsg,
+};
 use cw2::set_contract_version;
+use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg, OwnerOfResponse, TokensResponse};
+use cw_utils::{nonpayable, Duration, Expiration, Scheduled};
 
+use crate::auth::{grant_role, has_any_role, only_owner, only_roles, revoke_role};
+use crate::deposit::{burn_deposit, settle_deposit, should_refund, take_deposit};
 use crate::error::ContractError;
 use crate::helpers::DaoContract;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg};
+use crate::migrations;
+use crate::msg::{ConfigField, ExecuteMsg, InstantiateMsg, MigrateMsg};
+use crate::power::{
+    add_delegated_power, add_staked_power, staked_voting_power, sub_delegated_power,
+    sub_staked_power,
+};
 use crate::state::{
-    Candidate, Config, Propsal, PropsalStatus, Vote, BLOCK_HEIGHTS, BLOCK_INDEX, CONFIG, PROPSALS,
-    PROPSAL_INDEX,
+    votes, Candidate, Config, CreditAllocation, DepositDenom, NftClaim, Ownership, PowerSource,
+    ProposalDeposit, Propsal, PropsalResultStatus, PropsalStatus, Quorum, Role, TieBreaker, Vote,
+    VoteOption, VotingStrategy, BLOCK_HEIGHTS, BLOCK_INDEX, CANDIDATE_VOTERS, CONFIG, DELEGATES,
+    DELEGATIONS, NFT_CLAIMS, OVERRIDDEN_POWER, OWNERSHIP, PROPSALS, PROPSAL_COUNT, PROPSAL_INDEX,
+    PROPSAL_RESULTS, STAKED_NFTS, STAKED_POWER, TOTAL_STAKED, VOTED_TOKENS,
 };
+use crate::tally::{add_vote_tally, compute_result, sub_vote_tally};
 
 const CONTRACT_NAME: &str = "nft-dao";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -21,31 +39,59 @@ pub fn instantiate(
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    validate_voting_rules(&msg.quorum, &msg.threshold)?;
+    validate_max_durations(&msg.max_duration, &msg.max_duration_secondary)?;
+    let voting_strategy = msg.voting_strategy.unwrap_or_default();
+    validate_voting_strategy(&voting_strategy)?;
     let config = Config {
         start_blocknumber: env.block.height,
-        max_duration_seconds: msg.max_duration_seconds,
-        owner: deps.api.addr_validate(msg.owner.as_str())?,
+        max_duration: msg.max_duration,
+        max_duration_secondary: msg.max_duration_secondary,
+        quorum: msg.quorum,
+        threshold: msg.threshold,
+        voting_strategy,
+        timelock_seconds: msg.timelock_seconds,
+        nft_address: msg
+            .nft_address
+            .map(|nft_address| deps.api.addr_validate(&nft_address))
+            .transpose()?,
+        unbonding_period: msg.unbonding_period,
+        proposal_deposit: msg
+            .proposal_deposit
+            .map(|proposal_deposit| validate_proposal_deposit(deps.as_ref(), proposal_deposit))
+            .transpose()?,
     };
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    let owner = deps.api.addr_validate(msg.owner.as_str())?;
+    let ownership = Ownership {
+        owner: Some(owner.clone()),
+        pending_owner: None,
+        pending_expiry: None,
+    };
+
     CONFIG.save(deps.storage, &config)?;
+    OWNERSHIP.save(deps.storage, &ownership)?;
+    for guardian in msg.guardians {
+        let guardian = deps.api.addr_validate(&guardian)?;
+        grant_role(deps.storage, &guardian, Role::Guardian)?;
+    }
     PROPSAL_INDEX.save(deps.storage, &1u64)?;
+    PROPSAL_COUNT.save(deps.storage, &0u64)?;
     BLOCK_HEIGHTS.save(deps.storage, 0u64, &env.block.height)?;
     BLOCK_INDEX.save(deps.storage, &1u64)?;
 
     Ok(Response::new()
         .add_attribute("action", "instantiate")
         .add_attribute("sender", info.sender)
-        .add_attribute("owner", config.owner)
-        .add_attribute(
-            "max_duration_seconds",
-            config.max_duration_seconds.to_string(),
-        ))
+        .add_attribute("owner", owner)
+        .add_attribute("max_duration", config.max_duration.to_string())
+        .add_attribute("timelock_seconds", config.timelock_seconds.to_string()))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let ver = cw2::get_contract_version(deps.storage)?;
 
     if ver.contract != CONTRACT_NAME {
@@ -56,12 +102,20 @@ pub fn migrate(deps: DepsMut, _env: Env, _: MigrateMsg) -> Result<Response, Cont
         return Err(ContractError::InvalidContractVersion {});
     }
 
+    // 0.1.x 는 제안 아이디가 증가하지 않고 투표가 제안 안에 저장됨
+    let recovered = if ver.version.starts_with("0.1.") {
+        migrations::migrate_v0_1(deps.branch(), msg.recover_propsals)?
+    } else {
+        vec![]
+    };
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     Ok(Response::new()
         .add_attribute("action", "migrate")
         .add_attribute("name", CONTRACT_NAME)
-        .add_attribute("version", CONTRACT_VERSION))
+        .add_attribute("version", CONTRACT_VERSION)
+        .add_attribute("recovered_propsals", format!("{:?}", recovered)))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -73,15 +127,44 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::UpdateConfig {
-            owner,
-            max_duration_seconds,
-        } => update_config(deps, env, info, owner, max_duration_seconds),
+            max_duration,
+            max_duration_secondary,
+            quorum,
+            threshold,
+            timelock_seconds,
+            nft_address,
+            unbonding_period,
+            voting_strategy,
+            proposal_deposit,
+        } => update_config(
+            deps,
+            env,
+            info,
+            max_duration,
+            max_duration_secondary,
+            quorum,
+            threshold,
+            timelock_seconds,
+            nft_address,
+            unbonding_period,
+            voting_strategy,
+            proposal_deposit,
+        ),
+        ExecuteMsg::ClearConfig { fields } => clear_config(deps, env, info, fields),
         ExecuteMsg::AddPropsal {
             title,
             status,
             nft_address,
             expiration,
             candidates,
+            allow_revoting,
+            tie_breaker,
+            voting_starts_at,
+            quorum,
+            threshold,
+            power_source,
+            voting_strategy,
+            seats,
         } => add_propsal(
             deps,
             env,
@@ -91,6 +174,14 @@ pub fn execute(
             nft_address,
             expiration,
             candidates,
+            allow_revoting,
+            tie_breaker,
+            voting_starts_at,
+            quorum,
+            threshold,
+            power_source,
+            voting_strategy,
+            seats,
         ),
         ExecuteMsg::UpdatePropsal {
             id,
@@ -114,26 +205,171 @@ pub fn execute(
         ExecuteMsg::ExecuteVote {
             propsal_id,
             candidate_id,
-        } => execute_vote(deps, env, info, propsal_id, candidate_id),
+            token_ids,
+        } => execute_vote(
+            deps,
+            env,
+            info,
+            propsal_id,
+            Ballot::Single { candidate_id },
+            token_ids,
+        ),
+        ExecuteMsg::ExecuteQuadraticVote {
+            propsal_id,
+            allocations,
+            token_ids,
+        } => execute_vote(
+            deps,
+            env,
+            info,
+            propsal_id,
+            Ballot::Quadratic { allocations },
+            token_ids,
+        ),
+        ExecuteMsg::ExecuteRankedVote {
+            propsal_id,
+            ranking,
+            token_ids,
+        } => execute_vote(
+            deps,
+            env,
+            info,
+            propsal_id,
+            Ballot::Ranked { ranking },
+            token_ids,
+        ),
+        ExecuteMsg::ExecuteApprovalVote {
+            propsal_id,
+            candidate_ids,
+            token_ids,
+        } => execute_vote(
+            deps,
+            env,
+            info,
+            propsal_id,
+            Ballot::Approval { candidate_ids },
+            token_ids,
+        ),
+        ExecuteMsg::ExecuteSingleChoiceVote {
+            propsal_id,
+            option,
+            token_ids,
+        } => execute_vote(
+            deps,
+            env,
+            info,
+            propsal_id,
+            Ballot::SingleChoice { option },
+            token_ids,
+        ),
+        ExecuteMsg::Finalize { id } => finalize(deps, env, info, id),
+        ExecuteMsg::ExecuteProposal { id } => execute_proposal(deps, env, info, id),
+        ExecuteMsg::ExecutePropsalMsgs { id } => execute_propsal_msgs(deps, env, info, id),
+        ExecuteMsg::CancelProposal { id } => cancel_proposal(deps, env, info, id),
+        ExecuteMsg::Veto { id, reason } => veto(deps, env, info, id, reason),
+        ExecuteMsg::GrantRole { address, role } => {
+            execute_grant_role(deps, env, info, address, role)
+        }
+        ExecuteMsg::RevokeRole { address, role } => {
+            execute_revoke_role(deps, env, info, address, role)
+        }
+        ExecuteMsg::ProposeNewOwner { new_owner, expiry } => {
+            propose_new_owner(deps, env, info, new_owner, expiry)
+        }
+        ExecuteMsg::AcceptOwnership {} => accept_ownership(deps, env, info),
+        ExecuteMsg::CancelOwnershipTransfer {} => cancel_ownership_transfer(deps, env, info),
+        ExecuteMsg::RenounceOwnership {} => renounce_ownership(deps, env, info),
+        ExecuteMsg::ReceiveNft(msg) => receive_nft(deps, env, info, msg),
+        ExecuteMsg::Unstake { token_ids } => unstake(deps, env, info, token_ids),
+        ExecuteMsg::ClaimNfts {} => claim_nfts(deps, env, info),
+        ExecuteMsg::Delegate { to } => delegate(deps, env, info, to),
+        ExecuteMsg::Undelegate {} => undelegate(deps, env, info),
     }
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    // 실행 메시지 실패 시에만 호출 (reply_on_error), reply id = 제안 아이디
+    let id = msg.id;
+    let mut propsal = PROPSALS.load(deps.storage, id)?;
+    propsal.status = PropsalStatus::ExecutionFailed;
+    PROPSALS.save(deps.storage, id, &propsal)?;
+
+    let error = match msg.result {
+        SubMsgResult::Err(error) => error,
+        SubMsgResult::Ok(_) => String::new(),
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "reply")
+        .add_attribute("id", id.to_string())
+        .add_attribute("status", format!("{:?}", &propsal.status))
+        .add_attribute("error", error))
+}
+
 /// 컨트랙트 설정 업데이트
+#[allow(clippy::too_many_arguments)]
 fn update_config(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    owner: Option<String>,
-    max_duration_seconds: Option<u64>,
+    max_duration: Option<Duration>,
+    max_duration_secondary: Option<Duration>,
+    quorum: Option<Quorum>,
+    threshold: Option<Decimal>,
+    timelock_seconds: Option<u64>,
+    nft_address: Option<String>,
+    unbonding_period: Option<Duration>,
+    voting_strategy: Option<VotingStrategy>,
+    proposal_deposit: Option<ProposalDeposit>,
 ) -> Result<Response, ContractError> {
-    only_owner(deps.as_ref(), &info)?;
+    only_roles(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        &[Role::Admin, Role::ConfigManager],
+    )?;
+    validate_voting_rules(&quorum, &threshold)?;
     let mut config: Config = CONFIG.load(deps.storage)?;
 
-    if let Some(owner) = owner.clone() {
-        config.owner = deps.api.addr_validate(&owner)?;
+    if let Some(max_duration) = max_duration {
+        config.max_duration = max_duration;
+    }
+    if max_duration_secondary.is_some() {
+        config.max_duration_secondary = max_duration_secondary;
+    }
+    validate_max_durations(&config.max_duration, &config.max_duration_secondary)?;
+    if quorum.is_some() {
+        config.quorum = quorum;
+    }
+    if threshold.is_some() {
+        config.threshold = threshold;
+    }
+    if let Some(timelock_seconds) = timelock_seconds {
+        config.timelock_seconds = timelock_seconds;
     }
-    if let Some(max_duration_seconds) = max_duration_seconds.clone() {
-        config.max_duration_seconds = max_duration_seconds;
+    if let Some(nft_address) = nft_address {
+        // 스테이킹, 반환 대기 중인 nft 는 기존 컬렉션으로 돌려줘야 하므로 변경 불가
+        let nft_address = deps.api.addr_validate(&nft_address)?;
+        let staking = TOTAL_STAKED.may_load(deps.storage)?.unwrap_or_default() > 0
+            || NFT_CLAIMS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .next()
+                .is_some();
+        if staking && config.nft_address.as_ref() != Some(&nft_address) {
+            return Err(ContractError::NftStaked {});
+        }
+        config.nft_address = Some(nft_address);
+    }
+    if unbonding_period.is_some() {
+        config.unbonding_period = unbonding_period;
+    }
+    if let Some(voting_strategy) = voting_strategy {
+        validate_voting_strategy(&voting_strategy)?;
+        config.voting_strategy = voting_strategy;
+    }
+    if let Some(proposal_deposit) = proposal_deposit {
+        config.proposal_deposit = Some(validate_proposal_deposit(deps.as_ref(), proposal_deposit)?);
     }
 
     CONFIG.save(deps.storage, &config)?;
@@ -142,14 +378,62 @@ fn update_config(
     Ok(Response::new()
         .add_attribute("action", "update_config")
         .add_attribute("sender", info.sender)
-        .add_attribute("owner", config.owner)
+        .add_attribute("max_duration", config.max_duration.to_string())
         .add_attribute(
-            "max_duration_seconds",
-            config.max_duration_seconds.to_string(),
+            "max_duration_secondary",
+            format!("{:?}", &config.max_duration_secondary),
+        )
+        .add_attribute("quorum", format!("{:?}", &config.quorum))
+        .add_attribute("threshold", format!("{:?}", &config.threshold))
+        .add_attribute("timelock_seconds", config.timelock_seconds.to_string())
+        .add_attribute("nft_address", format!("{:?}", &config.nft_address))
+        .add_attribute(
+            "unbonding_period",
+            format!("{:?}", &config.unbonding_period),
+        )
+        .add_attribute("voting_strategy", format!("{:?}", &config.voting_strategy))
+        .add_attribute(
+            "proposal_deposit",
+            format!("{:?}", &config.proposal_deposit),
         ))
 }
 
+/// 선택 설정 초기화
+fn clear_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    fields: Vec<ConfigField>,
+) -> Result<Response, ContractError> {
+    only_roles(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        &[Role::Admin, Role::ConfigManager],
+    )?;
+    let mut config: Config = CONFIG.load(deps.storage)?;
+
+    for field in &fields {
+        match field {
+            ConfigField::MaxDurationSecondary => config.max_duration_secondary = None,
+            ConfigField::Quorum => config.quorum = None,
+            ConfigField::Threshold => config.threshold = None,
+            ConfigField::UnbondingPeriod => config.unbonding_period = None,
+            ConfigField::ProposalDeposit => config.proposal_deposit = None,
+        }
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "clear_config")
+        .add_attribute("sender", info.sender)
+        .add_attribute("fields", format!("{:?}", fields)))
+}
+
 /// 투표 제안 추가
+#[allow(clippy::too_many_arguments)]
 fn add_propsal(
     deps: DepsMut,
     env: Env,
@@ -157,36 +441,112 @@ fn add_propsal(
     title: String,
     status: PropsalStatus,
     nft_address: String,
-    expiration: u64,
+    expiration: Expiration,
     candidates: Vec<Candidate>,
+    allow_revoting: bool,
+    tie_breaker: Option<TieBreaker>,
+    voting_starts_at: Option<Scheduled>,
+    quorum: Option<Quorum>,
+    threshold: Option<Decimal>,
+    power_source: Option<PowerSource>,
+    voting_strategy: Option<VotingStrategy>,
+    seats: Option<u32>,
 ) -> Result<Response, ContractError> {
-    only_owner(deps.as_ref(), &info)?;
+    only_open_status(&status)?;
+    validate_voting_rules(&quorum, &threshold)?;
 
     let valid_nft_address = deps.api.addr_validate(&nft_address)?;
 
+    let config = CONFIG.load(deps.storage)?;
+    validate_expiration(&env, &config, &expiration, &voting_starts_at)?;
+
+    // 역할이 없으면 거버넌스 nft 보유자만 보증금을 내고 제안 가능
+    // 보유자 제안은 투표 규칙을 바꿀 수 없고 Config 기본값만 사용
+    let (deposit, deposit_msgs) = if has_any_role(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        &[Role::Admin, Role::ProposalCreator],
+    )? {
+        // 역할 보유자는 보증금을 내지 않으므로 보낸 자금이 남지 않도록 거부
+        nonpayable(&info)?;
+        (None, vec![])
+    } else {
+        if quorum.is_some()
+            || threshold.is_some()
+            || power_source.is_some()
+            || voting_strategy.is_some()
+        {
+            return Err(ContractError::VotingRulesOverride {});
+        }
+        only_nft_holder(
+            deps.as_ref(),
+            &config.nft_address,
+            &valid_nft_address,
+            &info.sender,
+        )?;
+        match &config.proposal_deposit {
+            Some(proposal_deposit) => {
+                let (deposit, msgs) = take_deposit(&env, &info, proposal_deposit)?;
+                (Some(deposit), msgs)
+            }
+            None => {
+                nonpayable(&info)?;
+                (None, vec![])
+            }
+        }
+    };
+
+    let voting_strategy = voting_strategy.unwrap_or(config.voting_strategy.clone());
+    validate_voting_strategy(&voting_strategy)?;
+    let candidates = propsal_candidates(&voting_strategy, candidates);
+    let seats = seats.unwrap_or(1);
+    validate_seats(&voting_strategy, seats, &candidates)?;
+
+    // 스테이킹 power 는 거버넌스 nft 제안에서만 사용, 등록 시점 스냅샷 기준
+    // 거버넌스 nft 제안은 기본적으로 스테이킹 power 사용
+    let governing = config.nft_address.as_ref() == Some(&valid_nft_address);
+    let power_source = power_source.unwrap_or(match governing {
+        true => PowerSource::Staked,
+        false => PowerSource::TokenIds,
+    });
+    if power_source == PowerSource::Staked && !governing {
+        return Err(ContractError::InvalidStakingNft {});
+    }
+
     let id = PROPSAL_INDEX.load(deps.storage)?;
     let propsal = Propsal {
         id,
         title,
-        status,
+        status: pending_status(status, &voting_starts_at, &env),
         nft_address: valid_nft_address,
         expiration,
-        votes: vec![],
+        voting_starts_at,
+        start_height: env.block.height,
+        power_source,
+        voting_strategy,
+        seats,
         candidates,
+        allow_revoting,
+        tie_breaker,
+        quorum: quorum.or(config.quorum.clone()),
+        threshold: threshold.or(config.threshold),
+        total_power: 0,
+        voter_count: 0,
+        vote_count: 0,
+        executable_at: None,
+        veto_reason: None,
+        proposer: info.sender.clone(),
+        deposit,
     };
 
-    let config = CONFIG.load(deps.storage)?;
-    if expiration < env.block.time.seconds() {
-        return Err(ContractError::LowerTime {});
-    }
-    if (expiration - env.block.time.seconds()) > config.max_duration_seconds {
-        return Err(ContractError::MaxDuration {});
-    }
-
     PROPSALS.save(deps.storage, id, &propsal)?;
+    PROPSAL_INDEX.save(deps.storage, &(id + 1))?;
+    PROPSAL_COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
     DaoContract::increase_block(deps, &env)?;
 
     Ok(Response::new()
+        .add_messages(deposit_msgs)
         .add_attribute("action", "add_propsal")
         .add_attribute("sender", info.sender)
         .add_attribute("id", propsal.id.to_string())
@@ -194,10 +554,22 @@ fn add_propsal(
         .add_attribute("status", format!("{:?}", &propsal.status))
         .add_attribute("nft_address", nft_address)
         .add_attribute("expiration", expiration.to_string())
-        .add_attribute("candidates", format!("{:?}", &propsal.candidates)))
+        .add_attribute(
+            "voting_starts_at",
+            format!("{:?}", &propsal.voting_starts_at),
+        )
+        .add_attribute("candidates", format!("{:?}", &propsal.candidates))
+        .add_attribute("allow_revoting", allow_revoting.to_string())
+        .add_attribute("tie_breaker", format!("{:?}", &propsal.tie_breaker))
+        .add_attribute("voting_strategy", format!("{:?}", &propsal.voting_strategy))
+        .add_attribute("seats", propsal.seats.to_string())
+        .add_attribute("quorum", format!("{:?}", &propsal.quorum))
+        .add_attribute("threshold", format!("{:?}", &propsal.threshold))
+        .add_attribute("deposit", format!("{:?}", &propsal.deposit)))
 }
 
 /// 투표 제안 업데이트
+#[allow(clippy::too_many_arguments)]
 fn update_propsal(
     deps: DepsMut,
     env: Env,
@@ -206,40 +578,57 @@ fn update_propsal(
     title: Option<String>,
     status: Option<PropsalStatus>,
     nft_address: Option<String>,
-    expiration: Option<u64>,
+    expiration: Option<Expiration>,
     candidates: Option<Vec<Candidate>>,
 ) -> Result<Response, ContractError> {
-    only_owner(deps.as_ref(), &info)?;
+    only_roles(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        &[Role::Admin, Role::ProposalCreator],
+    )?;
 
     let mut propsal: Propsal = PROPSALS.load(deps.storage, id)?;
+    if !propsal.status.is_open() {
+        return Err(ContractError::PropsalFinalized {});
+    }
+    // 투표가 시작된 후에는 투표 대상 (후보, 메시지, nft) 변경 불가
+    if propsal.voter_count > 0 && (candidates.is_some() || nft_address.is_some()) {
+        return Err(ContractError::PropsalLocked {});
+    }
+
     if let Some(title) = title.clone() {
         propsal.title = title.clone();
     }
     if let Some(status) = status.clone() {
-        propsal.status = status.clone();
+        only_open_status(&status)?;
+        propsal.status = pending_status(status, &propsal.voting_starts_at, &env);
     }
     if let Some(nft_address) = nft_address.clone() {
         let valid_nft_address = deps.api.addr_validate(&nft_address)?;
+        // 스테이킹 power 제안은 거버넌스 nft 로만 변경 가능
+        let config = CONFIG.load(deps.storage)?;
+        if propsal.power_source == PowerSource::Staked
+            && config.nft_address.as_ref() != Some(&valid_nft_address)
+        {
+            return Err(ContractError::InvalidStakingNft {});
+        }
         propsal.nft_address = valid_nft_address.clone();
     }
 
-    if let Some(expiration) = expiration.clone() {
-        // 만료시간 유효성 검사
+    if let Some(expiration) = expiration {
         let config = CONFIG.load(deps.storage)?;
-        if expiration < env.block.time.seconds() {
-            return Err(ContractError::LowerTime {});
-        }
-        if (expiration - env.block.time.seconds()) > config.max_duration_seconds {
-            return Err(ContractError::MaxDuration {});
-        }
-        propsal.expiration = expiration.clone();
+        validate_expiration(&env, &config, &expiration, &propsal.voting_starts_at)?;
+        propsal.expiration = expiration;
     }
 
     if let Some(candidates) = candidates.clone() {
-        propsal.candidates = candidates.clone();
+        let candidates = propsal_candidates(&propsal.voting_strategy, candidates);
+        validate_seats(&propsal.voting_strategy, propsal.seats, &candidates)?;
+        propsal.candidates = candidates;
     }
 
-    PROPSALS.save(deps.storage, id.clone(), &propsal)?;
+    PROPSALS.save(deps.storage, id, &propsal)?;
     DaoContract::increase_block(deps, &env)?;
 
     Ok(Response::new()
@@ -260,53 +649,1113 @@ fn remove_propsal(
     info: MessageInfo,
     id: u64,
 ) -> Result<Response, ContractError> {
-    only_owner(deps.as_ref(), &info)?;
+    only_roles(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        &[Role::Admin, Role::ProposalCreator],
+    )?;
+
+    // 보관 중인 보증금은 제안자에게 반환
+    let refund_msgs = match PROPSALS.may_load(deps.storage, id)? {
+        Some(mut propsal) => settle_deposit(&mut propsal.deposit, &propsal.proposer, true)?,
+        None => vec![],
+    };
 
-    PROPSALS.remove(deps.storage, id.clone());
+    PROPSALS.remove(deps.storage, id);
     DaoContract::increase_block(deps, &env)?;
 
     Ok(Response::new()
+        .add_messages(refund_msgs)
         .add_attribute("action", "remove_propsal")
         .add_attribute("sender", info.sender)
         .add_attribute("id", id.to_string()))
 }
 
+/// 투표 용지
+enum Ballot {
+    /// 한 후보 선택
+    Single { candidate_id: u64 },
+    /// 후보별 credit 배분 (제곱근 투표)
+    Quadratic { allocations: Vec<CreditAllocation> },
+    /// 후보 순위 (순위 투표)
+    Ranked { ranking: Vec<u64> },
+    /// 승인한 후보 (승인 투표)
+    Approval { candidate_ids: Vec<u64> },
+    /// 찬반 선택지 (찬반 투표)
+    SingleChoice { option: VoteOption },
+}
+
 /// 투표 실행
 fn execute_vote(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     propsal_id: u64,
-    candidate_id: u64,
+    ballot: Ballot,
+    token_ids: Vec<String>,
 ) -> Result<Response, ContractError> {
     let mut propsal = PROPSALS.load(deps.storage, propsal_id)?;
+    propsal.vote_count += 1;
+
+    // 투표 가능 상태 (시작, 마감기한 포함), 투표 용지 유효성 검사
+    match propsal.current_status(&env.block) {
+        PropsalStatus::Enabled => {}
+        PropsalStatus::Pending => return Err(ContractError::VotingNotStarted {}),
+        PropsalStatus::Disabled => return Err(ContractError::StatusDisabled {}),
+        _ => return Err(ContractError::VotingClosed {}),
+    }
+    validate_ballot(&propsal, &ballot)?;
+
+    // 재투표는 allow_revoting 인 경우에만 허용
+    let prev_vote = votes().may_load(deps.storage, (propsal_id, &info.sender))?;
+    if prev_vote.is_some() && !propsal.allow_revoting {
+        return Err(ContractError::AlreadyVoted {});
+    }
 
-    let vote = Vote {
-        /// 1 NFT = 1 power
-        power: 1,
-        voter: info.clone().sender,
-        propsal_id,
-        candidate_id,
+    let mut voted_token_ids = vec![];
+    let new_power = match propsal.power_source {
+        PowerSource::TokenIds => {
+            // 이미 투표한 토큰은 제외, 1 NFT = 1 power
+            let mut token_ids = token_ids;
+            token_ids.sort();
+            token_ids.dedup();
+            for token_id in token_ids {
+                if VOTED_TOKENS.has(deps.storage, (propsal_id, &token_id)) {
+                    continue;
+                }
+                only_token_owner(deps.as_ref(), &propsal.nft_address, &token_id, &info.sender)?;
+                VOTED_TOKENS.save(deps.storage, (propsal_id, &token_id), &info.sender)?;
+                voted_token_ids.push(token_id);
+            }
+            voted_token_ids.len() as u64
+        }
+        // 재투표 시 power 는 그대로
+        PowerSource::Staked if prev_vote.is_some() => 0,
+        PowerSource::Staked => {
+            let power = staked_voting_power(deps.storage, &propsal, &info.sender)?;
+            override_delegate(deps.storage, &mut propsal, &info.sender)?;
+            power
+        }
     };
 
-    propsal.votes.append(&mut vec![vote.clone()]);
+    let mut vote = match prev_vote.clone() {
+        // 이전 투표의 토큰을 유지한 채 후보 변경
+        Some(mut vote) => {
+            // 이전 투표의 득표 차감
+            sub_vote_tally(deps.storage, &propsal, &vote)?;
+            vote.power += new_power;
+            vote.token_ids.append(&mut voted_token_ids);
+            vote
+        }
+        None => {
+            if new_power == 0 {
+                return Err(ContractError::NoVotingPower {});
+            }
+            propsal.voter_count += 1;
+            Vote {
+                power: new_power,
+                voter: info.sender.clone(),
+                propsal_id,
+                candidate_id: 0,
+                token_ids: voted_token_ids,
+                credits: vec![],
+                ranking: vec![],
+                approvals: vec![],
+            }
+        }
+    };
+    match ballot {
+        Ballot::Single { candidate_id } => vote.candidate_id = candidate_id,
+        Ballot::SingleChoice { option } => vote.candidate_id = option.candidate_id(),
+        Ballot::Quadratic { allocations } => {
+            // 배분한 credit 은 power 를 넘을 수 없음
+            let spent = total_credits(&allocations).ok_or(ContractError::InvalidBallot {})?;
+            if spent > vote.power {
+                return Err(ContractError::InsufficientCredits {
+                    spent,
+                    available: vote.power,
+                });
+            }
+            vote.candidate_id = top_allocation(&allocations);
+            vote.credits = allocations;
+        }
+        Ballot::Ranked { ranking } => {
+            vote.candidate_id = ranking[0];
+            vote.ranking = ranking;
+        }
+        Ballot::Approval { candidate_ids } => {
+            vote.candidate_id = candidate_ids[0];
+            vote.approvals = candidate_ids;
+        }
+    }
+
+    propsal.total_power += new_power;
+    add_vote_tally(deps.storage, &propsal, &vote)?;
 
-    PROPSALS.save(deps.storage, propsal_id.clone(), &propsal)?;
+    save_vote(deps.storage, &prev_vote, &vote)?;
+    PROPSALS.save(deps.storage, propsal_id, &propsal)?;
     DaoContract::increase_block(deps, &env)?;
 
     Ok(Response::new()
         .add_attribute("action", "execute_vote")
-        .add_attribute("sender", info.clone().sender)
-        .add_attribute("propsal_id", vote.clone().propsal_id.to_string())
-        .add_attribute("candidate_id", format!("{:?}", &propsal.candidates)))
+        .add_attribute("sender", info.sender)
+        .add_attribute("propsal_id", vote.propsal_id.to_string())
+        .add_attribute("candidate_id", vote.candidate_id.to_string())
+        .add_attribute("power", vote.power.to_string())
+        .add_attribute("token_ids", format!("{:?}", &vote.token_ids))
+        .add_attribute("credits", format!("{:?}", &vote.credits))
+        .add_attribute("ranking", format!("{:?}", &vote.ranking))
+        .add_attribute("approvals", format!("{:?}", &vote.approvals)))
+}
+
+/// 투표와 후보별 투표자 인덱스 저장, 재투표면 이전 후보 인덱스 삭제
+fn save_vote(storage: &mut dyn Storage, prev_vote: &Option<Vote>, vote: &Vote) -> StdResult<()> {
+    if let Some(prev_vote) = prev_vote {
+        for candidate_id in prev_vote.candidate_ids() {
+            CANDIDATE_VOTERS.remove(storage, (vote.propsal_id, candidate_id, &vote.voter));
+        }
+    }
+    for candidate_id in vote.candidate_ids() {
+        CANDIDATE_VOTERS.save(
+            storage,
+            (vote.propsal_id, candidate_id, &vote.voter),
+            &Empty {},
+        )?;
+    }
+
+    votes().save(storage, (vote.propsal_id, &vote.voter), vote)
+}
+
+/// 투표 용지가 제안의 집계 방식, 후보와 맞는지 검사
+fn validate_ballot(propsal: &Propsal, ballot: &Ballot) -> Result<(), ContractError> {
+    let candidate_ids: Vec<u64> = match (&propsal.voting_strategy, ballot) {
+        (VotingStrategy::Plurality, Ballot::Single { candidate_id }) => vec![*candidate_id],
+        (VotingStrategy::SingleChoice { .. }, Ballot::SingleChoice { option }) => {
+            vec![option.candidate_id()]
+        }
+        (VotingStrategy::Quadratic, Ballot::Quadratic { allocations }) => {
+            if allocations.is_empty()
+                || allocations.iter().any(|a| a.credits == 0)
+                || total_credits(allocations).is_none()
+            {
+                return Err(ContractError::InvalidBallot {});
+            }
+            allocations.iter().map(|a| a.candidate_id).collect()
+        }
+        (
+            VotingStrategy::RankedChoice | VotingStrategy::SingleTransferableVote,
+            Ballot::Ranked { ranking },
+        ) if !ranking.is_empty() => ranking.clone(),
+        (VotingStrategy::Approval { max_choices }, Ballot::Approval { candidate_ids })
+            if !candidate_ids.is_empty() =>
+        {
+            if let Some(max_choices) = max_choices {
+                if candidate_ids.len() > *max_choices as usize {
+                    return Err(ContractError::TooManyChoices {
+                        max_choices: *max_choices,
+                    });
+                }
+            }
+            candidate_ids.clone()
+        }
+        _ => return Err(ContractError::InvalidBallot {}),
+    };
+
+    let mut seen = vec![];
+    for candidate_id in candidate_ids {
+        if !propsal.candidates.iter().any(|c| c.id == candidate_id) {
+            return Err(ContractError::InvalidCandidate { candidate_id });
+        }
+        if seen.contains(&candidate_id) {
+            return Err(ContractError::InvalidBallot {});
+        }
+        seen.push(candidate_id);
+    }
+
+    Ok(())
+}
+
+/// credit 을 가장 많이 배분한 후보 (같으면 후보 인덱스가 작은 후보)
+fn top_allocation(allocations: &[CreditAllocation]) -> u64 {
+    allocations
+        .iter()
+        .max_by(|a, b| {
+            a.credits
+                .cmp(&b.credits)
+                .then(b.candidate_id.cmp(&a.candidate_id))
+        })
+        .map(|a| a.candidate_id)
+        .unwrap_or_default()
+}
+
+/// 배분한 credit 의 합 (넘치면 None)
+fn total_credits(allocations: &[CreditAllocation]) -> Option<u64> {
+    allocations
+        .iter()
+        .try_fold(0u64, |spent, a| spent.checked_add(a.credits))
+}
+
+/// power 가 줄어든 경우 뒤쪽 배분부터 credit 을 줄임
+fn trim_credits(allocations: &mut Vec<CreditAllocation>, available: u64) {
+    let mut remaining = available;
+    for allocation in allocations.iter_mut() {
+        allocation.credits = allocation.credits.min(remaining);
+        remaining -= allocation.credits;
+    }
+    allocations.retain(|a| a.credits > 0);
+}
+
+/// 위임자가 직접 투표하면 이 제안에서만 대리인 투표에서 위임자의 power 를 제외
+fn override_delegate(
+    storage: &mut dyn Storage,
+    propsal: &mut Propsal,
+    voter: &Addr,
+) -> StdResult<()> {
+    let delegate = match DELEGATES.may_load_at_height(storage, voter, propsal.start_height)? {
+        Some(delegate) => delegate,
+        None => return Ok(()),
+    };
+    let power = STAKED_POWER
+        .may_load_at_height(storage, voter, propsal.start_height)?
+        .unwrap_or_default();
+    if power == 0 {
+        return Ok(());
+    }
+
+    OVERRIDDEN_POWER.update(
+        storage,
+        (propsal.id, &delegate),
+        |overridden| -> StdResult<_> { Ok(overridden.unwrap_or_default() + power) },
+    )?;
+    // 대리인이 이미 투표했다면 해당 투표에서 차감
+    if let Some(prev_vote) = votes().may_load(storage, (propsal.id, &delegate))? {
+        let mut vote = prev_vote.clone();
+        sub_vote_tally(storage, propsal, &vote)?;
+        vote.power -= power;
+        trim_credits(&mut vote.credits, vote.power);
+        if !vote.credits.is_empty() {
+            vote.candidate_id = top_allocation(&vote.credits);
+        }
+        add_vote_tally(storage, propsal, &vote)?;
+        propsal.total_power -= power;
+        save_vote(storage, &Some(prev_vote), &vote)?;
+    }
+
+    Ok(())
+}
+
+/// 투표 결과 확정
+fn finalize(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let mut propsal = PROPSALS.load(deps.storage, id)?;
+    if !propsal.status.is_open() {
+        return Err(ContractError::PropsalFinalized {});
+    }
+    if !propsal.expiration.is_expired(&env.block) {
+        return Err(ContractError::VotingNotEnded {});
+    }
+
+    let mut propsal_result = compute_result(deps.as_ref(), &propsal)?;
+
+    // 실행할 메시지가 있는 통과 제안은 타임락 후 실행
+    let executable = match &propsal_result.status {
+        PropsalResultStatus::Passed => propsal_result
+            .winners
+            .iter()
+            .any(|winner| !winner.msgs.is_empty()),
+        _ => false,
+    };
+    if executable {
+        let config = CONFIG.load(deps.storage)?;
+        propsal.status = PropsalStatus::Queued;
+        propsal.executable_at = Some(env.block.time.seconds() + config.timelock_seconds);
+    } else {
+        propsal.status = PropsalStatus::Finalized;
+    }
+    // 실행 대기 중인 제안의 보증금은 실행, 취소, 거부 시 처리
+    // NoWithVeto 로 거부된 제안의 보증금은 반환 정책과 관계없이 소각
+    let deposit_msgs = match (&propsal.status, &propsal.deposit) {
+        (PropsalStatus::Finalized, Some(_))
+            if propsal_result.status == PropsalResultStatus::Vetoed =>
+        {
+            burn_deposit(&mut propsal.deposit)?
+        }
+        (PropsalStatus::Finalized, Some(deposit)) => {
+            let refund = should_refund(&deposit.refund_policy, &propsal_result.status);
+            settle_deposit(&mut propsal.deposit, &propsal.proposer, refund)?
+        }
+        _ => vec![],
+    };
+    propsal_result.propsal = propsal.clone();
+
+    PROPSALS.save(deps.storage, id, &propsal)?;
+    PROPSAL_RESULTS.save(deps.storage, id, &propsal_result)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    let tally_events = propsal_result.tallies.iter().map(|tally| {
+        Event::new("propsal_tally")
+            .add_attribute("propsal_id", id.to_string())
+            .add_attribute("candidate_id", tally.candidate_id.to_string())
+            .add_attribute("name", tally.name.clone())
+            .add_attribute("power", tally.power.to_string())
+    });
+
+    Ok(Response::new()
+        .add_messages(deposit_msgs)
+        .add_attribute("action", "finalize")
+        .add_attribute("sender", info.sender)
+        .add_attribute("id", id.to_string())
+        .add_event(
+            Event::new("propsal_finalized")
+                .add_attribute("propsal_id", id.to_string())
+                .add_attribute("status", format!("{:?}", &propsal_result.status))
+                .add_attribute("outcome", format!("{:?}", &propsal_result.outcome))
+                .add_attribute(
+                    "winners",
+                    format!(
+                        "{:?}",
+                        propsal_result
+                            .winners
+                            .iter()
+                            .map(|winner| winner.id)
+                            .collect::<Vec<_>>()
+                    ),
+                )
+                .add_attribute("total_power", propsal.total_power.to_string())
+                .add_attribute("voter_count", propsal.voter_count.to_string())
+                .add_attribute(
+                    "executable_at",
+                    propsal
+                        .executable_at
+                        .map(|executable_at| executable_at.to_string())
+                        .unwrap_or_else(|| "none".to_string()),
+                ),
+        )
+        .add_events(tally_events))
 }
 
-/// 오너 체크
-fn only_owner(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+/// 타임락이 지난 대기 제안의 메시지 실행
+fn execute_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let mut propsal = PROPSALS.load(deps.storage, id)?;
+    match propsal.status {
+        PropsalStatus::Queued => {}
+        PropsalStatus::Executed | PropsalStatus::ExecutionFailed => {
+            return Err(ContractError::AlreadyExecuted {})
+        }
+        PropsalStatus::Cancelled => return Err(ContractError::PropsalCancelled {}),
+        PropsalStatus::Finalized => {
+            // 실행할 메시지가 없는 통과 제안은 대기 없이 종료
+            let propsal_result = PROPSAL_RESULTS.load(deps.storage, id)?;
+            return match propsal_result.status {
+                PropsalResultStatus::Passed => Err(ContractError::NothingToExecute {}),
+                _ => Err(ContractError::PropsalNotPassed {}),
+            };
+        }
+        _ => return Err(ContractError::PropsalNotPassed {}),
+    }
+
+    let executable_at = propsal.executable_at.unwrap_or_default();
+    if env.block.time.seconds() < executable_at {
+        return Err(ContractError::Timelocked { executable_at });
+    }
+
+    // 메시지는 자기 호출 하나로 묶어 하나라도 실패하면 모두 취소
+    // 실패 시 reply 에서 ExecutionFailed 로 변경
+    propsal.status = PropsalStatus::Executed;
+    let deposit_msgs = settle_deposit(&mut propsal.deposit, &propsal.proposer, true)?;
+    PROPSALS.save(deps.storage, id, &propsal)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    let batch_msg =
+        DaoContract(env.contract.address).call(ExecuteMsg::ExecutePropsalMsgs { id })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_proposal")
+        .add_attribute("sender", info.sender)
+        .add_attribute("id", id.to_string())
+        .add_submessage(SubMsg::reply_on_error(batch_msg, id))
+        .add_messages(deposit_msgs))
+}
+
+/// 당선 순서대로 당선자의 메시지 실행, ExecuteProposal 의 자기 호출로만 실행
+fn execute_propsal_msgs(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let propsal_result = PROPSAL_RESULTS.load(deps.storage, id)?;
+    let msgs: Vec<CosmosMsg> = propsal_result
+        .winners
+        .into_iter()
+        .flat_map(|winner| winner.msgs)
+        .collect();
+
+    Ok(Response::new()
+        .add_attribute("action", "execute_propsal_msgs")
+        .add_attribute("id", id.to_string())
+        .add_messages(msgs))
+}
+
+/// 실행 대기 중인 제안 취소
+fn cancel_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    only_roles(
+        deps.as_ref(),
+        &env,
+        &info.sender,
+        &[Role::Admin, Role::Guardian],
+    )?;
+
+    let mut propsal = PROPSALS.load(deps.storage, id)?;
+    if propsal.status != PropsalStatus::Queued {
+        return Err(ContractError::PropsalNotQueued {});
+    }
+
+    // 통과한 제안이므로 정책에 따라 보증금 반환
+    let propsal_result = PROPSAL_RESULTS.load(deps.storage, id)?;
+    let refund = propsal
+        .deposit
+        .as_ref()
+        .is_some_and(|deposit| should_refund(&deposit.refund_policy, &propsal_result.status));
+    let deposit_msgs = settle_deposit(&mut propsal.deposit, &propsal.proposer, refund)?;
+
+    propsal.status = PropsalStatus::Cancelled;
+    PROPSALS.save(deps.storage, id, &propsal)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(Response::new()
+        .add_messages(deposit_msgs)
+        .add_attribute("action", "cancel_proposal")
+        .add_attribute("sender", info.sender)
+        .add_attribute("id", id.to_string()))
+}
+
+/// 진행 중이거나 실행 대기 중인 제안에 거부권 행사
+fn veto(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    reason: String,
+) -> Result<Response, ContractError> {
+    only_roles(deps.as_ref(), &env, &info.sender, &[Role::Guardian])?;
+
+    let mut propsal = PROPSALS.load(deps.storage, id)?;
+    match propsal.status {
+        PropsalStatus::Pending
+        | PropsalStatus::Enabled
+        | PropsalStatus::Disabled
+        | PropsalStatus::Queued => {}
+        PropsalStatus::Executed | PropsalStatus::ExecutionFailed => {
+            return Err(ContractError::AlreadyExecuted {})
+        }
+        _ => {
+            return Err(ContractError::InvalidStatus {
+                status: format!("{:?}", &propsal.status),
+            })
+        }
+    }
+
+    propsal.status = PropsalStatus::Vetoed;
+    propsal.veto_reason = Some(reason.clone());
+    // 거부된 제안의 보증금은 DAO 에 귀속
+    settle_deposit(&mut propsal.deposit, &propsal.proposer, false)?;
+    PROPSALS.save(deps.storage, id, &propsal)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "veto")
+        .add_attribute("sender", info.sender.clone())
+        .add_attribute("id", id.to_string())
+        .add_event(
+            Event::new("propsal_vetoed")
+                .add_attribute("propsal_id", id.to_string())
+                .add_attribute("guardian", info.sender)
+                .add_attribute("reason", reason),
+        ))
+}
+
+/// 역할 부여
+fn execute_grant_role(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    role: Role,
+) -> Result<Response, ContractError> {
+    only_roles(deps.as_ref(), &env, &info.sender, &[Role::Admin])?;
+
+    let address = deps.api.addr_validate(&address)?;
+    let granted = grant_role(deps.storage, &address, role.clone())?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("sender", info.sender)
+        .add_attribute("address", address)
+        .add_attribute("role", format!("{:?}", &role))
+        .add_attribute("granted", granted.to_string()))
+}
+
+/// 역할 회수
+fn execute_revoke_role(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    role: Role,
+) -> Result<Response, ContractError> {
+    only_roles(deps.as_ref(), &env, &info.sender, &[Role::Admin])?;
+
+    let address = deps.api.addr_validate(&address)?;
+    let revoked = revoke_role(deps.storage, &address, &role)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("sender", info.sender)
+        .add_attribute("address", address)
+        .add_attribute("role", format!("{:?}", &role))
+        .add_attribute("revoked", revoked.to_string()))
+}
+
+/// 새 오너 지정
+fn propose_new_owner(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_owner: String,
+    expiry: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    only_owner(deps.storage, &info.sender)?;
+    if let Some(expiry) = &expiry {
+        if expiry.is_expired(&env.block) {
+            return Err(ContractError::TransferExpired {});
+        }
+    }
+
+    let mut ownership = OWNERSHIP.load(deps.storage)?;
+    ownership.pending_owner = Some(deps.api.addr_validate(&new_owner)?);
+    ownership.pending_expiry = expiry;
+    OWNERSHIP.save(deps.storage, &ownership)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(ownership_response("propose_new_owner", info, &ownership))
+}
+
+/// 오너 지정 수락
+fn accept_ownership(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let mut ownership = OWNERSHIP.load(deps.storage)?;
+    match &ownership.pending_owner {
+        None => return Err(ContractError::TransferNotFound {}),
+        Some(pending_owner) if *pending_owner != info.sender => {
+            return Err(ContractError::NotPendingOwner {})
+        }
+        Some(_) => {}
+    }
+    if let Some(expiry) = &ownership.pending_expiry {
+        if expiry.is_expired(&env.block) {
+            return Err(ContractError::TransferExpired {});
+        }
+    }
+
+    ownership.owner = ownership.pending_owner.take();
+    ownership.pending_expiry = None;
+    OWNERSHIP.save(deps.storage, &ownership)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(ownership_response("accept_ownership", info, &ownership))
+}
+
+/// 오너 지정 취소
+fn cancel_ownership_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    only_owner(deps.storage, &info.sender)?;
+
+    let mut ownership = OWNERSHIP.load(deps.storage)?;
+    if ownership.pending_owner.is_none() {
+        return Err(ContractError::TransferNotFound {});
+    }
+    ownership.pending_owner = None;
+    ownership.pending_expiry = None;
+    OWNERSHIP.save(deps.storage, &ownership)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(ownership_response(
+        "cancel_ownership_transfer",
+        info,
+        &ownership,
+    ))
+}
+
+/// 소유권 포기
+fn renounce_ownership(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    only_owner(deps.storage, &info.sender)?;
+
+    let ownership = Ownership {
+        owner: None,
+        pending_owner: None,
+        pending_expiry: None,
+    };
+    OWNERSHIP.save(deps.storage, &ownership)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(ownership_response("renounce_ownership", info, &ownership))
+}
+
+/// 소유권 변경 응답 (cw-ownable 과 같은 속성)
+fn ownership_response(action: &str, info: MessageInfo, ownership: &Ownership) -> Response {
+    let to_string = |addr: &Option<Addr>| {
+        addr.as_ref()
+            .map(|addr| addr.to_string())
+            .unwrap_or_else(|| "none".to_string())
+    };
+
+    Response::new()
+        .add_attribute("action", action)
+        .add_attribute("sender", info.sender)
+        .add_attribute("owner", to_string(&ownership.owner))
+        .add_attribute("pending_owner", to_string(&ownership.pending_owner))
+        .add_attribute(
+            "pending_expiry",
+            ownership
+                .pending_expiry
+                .map(|expiry| expiry.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+        )
+}
+
+/// 거버넌스 nft 스테이킹
+fn receive_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if config.owner != info.sender {
+    if config.nft_address.as_ref() != Some(&info.sender) {
+        return Err(ContractError::InvalidStakingNft {});
+    }
+
+    let staker = deps.api.addr_validate(&msg.sender)?;
+    STAKED_NFTS.save(deps.storage, (&staker, &msg.token_id), &Empty {})?;
+    add_staked_power(deps.storage, env.block.height, &staker, 1)?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "stake")
+        .add_attribute("staker", staker)
+        .add_attribute("token_id", msg.token_id))
+}
+
+/// 거버넌스 nft 스테이킹 power 위임, 이미 위임 중이면 대리인 변경
+fn delegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    to: String,
+) -> Result<Response, ContractError> {
+    let to = deps.api.addr_validate(&to)?;
+    if to == info.sender {
+        return Err(ContractError::SelfDelegation {});
+    }
+
+    let height = env.block.height;
+    let power = STAKED_POWER
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    if let Some(prev) = DELEGATES.may_load(deps.storage, &info.sender)? {
+        sub_delegated_power(deps.storage, height, &prev, power)?;
+        DELEGATIONS.remove(deps.storage, (&prev, &info.sender));
+    }
+    add_delegated_power(deps.storage, height, &to, power)?;
+    DELEGATES.save(deps.storage, &info.sender, &to, height)?;
+    DELEGATIONS.save(deps.storage, (&to, &info.sender), &Empty {})?;
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "delegate")
+        .add_attribute("sender", info.sender)
+        .add_attribute("delegate", to)
+        .add_attribute("power", power.to_string()))
+}
+
+/// 위임 해제
+fn undelegate(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let delegate = DELEGATES
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::NotDelegating {})?;
+
+    let height = env.block.height;
+    let power = STAKED_POWER
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    sub_delegated_power(deps.storage, height, &delegate, power)?;
+    DELEGATES.remove(deps.storage, &info.sender, height)?;
+    DELEGATIONS.remove(deps.storage, (&delegate, &info.sender));
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "undelegate")
+        .add_attribute("sender", info.sender)
+        .add_attribute("delegate", delegate)
+        .add_attribute("power", power.to_string()))
+}
+
+/// 스테이킹 해제, power 는 즉시 차감되고 nft 는 unbonding 기간 후 반환
+fn unstake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_ids: Vec<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let nft_address = config
+        .nft_address
+        .ok_or(ContractError::InvalidStakingNft {})?;
+
+    let mut token_ids = token_ids;
+    token_ids.sort();
+    token_ids.dedup();
+    for token_id in &token_ids {
+        if !STAKED_NFTS.has(deps.storage, (&info.sender, token_id)) {
+            return Err(ContractError::NotStaked {
+                token_id: token_id.clone(),
+            });
+        }
+        STAKED_NFTS.remove(deps.storage, (&info.sender, token_id));
+    }
+
+    sub_staked_power(
+        deps.storage,
+        env.block.height,
+        &info.sender,
+        token_ids.len() as u64,
+    )?;
+
+    let msgs = match config.unbonding_period {
+        Some(unbonding_period) => {
+            let release_at = unbonding_period.after(&env.block);
+            NFT_CLAIMS.update(deps.storage, &info.sender, |claims| -> StdResult<_> {
+                let mut claims = claims.unwrap_or_default();
+                claims.extend(token_ids.iter().map(|token_id| NftClaim {
+                    token_id: token_id.clone(),
+                    release_at,
+                }));
+                Ok(claims)
+            })?;
+            vec![]
+        }
+        None => token_ids
+            .iter()
+            .map(|token_id| transfer_nft_msg(&nft_address, &info.sender, token_id))
+            .collect::<StdResult<_>>()?,
+    };
+    DaoContract::increase_block(deps, &env)?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "unstake")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_ids", format!("{:?}", &token_ids)))
+}
+
+/// unbonding 기간이 지난 nft 반환
+fn claim_nfts(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let nft_address = config
+        .nft_address
+        .ok_or(ContractError::InvalidStakingNft {})?;
+
+    let (released, pending): (Vec<_>, Vec<_>) = NFT_CLAIMS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default()
+        .into_iter()
+        .partition(|claim| claim.release_at.is_expired(&env.block));
+    if released.is_empty() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    if pending.is_empty() {
+        NFT_CLAIMS.remove(deps.storage, &info.sender);
+    } else {
+        NFT_CLAIMS.save(deps.storage, &info.sender, &pending)?;
+    }
+
+    let msgs = released
+        .iter()
+        .map(|claim| transfer_nft_msg(&nft_address, &info.sender, &claim.token_id))
+        .collect::<StdResult<Vec<_>>>()?;
+    let token_ids: Vec<_> = released.into_iter().map(|claim| claim.token_id).collect();
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "claim_nfts")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_ids", format!("{:?}", &token_ids)))
+}
+
+fn transfer_nft_msg(nft_address: &Addr, recipient: &Addr, token_id: &str) -> StdResult<WasmMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: nft_address.to_string(),
+        msg: to_json_binary(&Cw721ExecuteMsg::TransferNft {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+        })?,
+        funds: vec![],
+    })
+}
+
+/// 보증금 설정 검사
+fn validate_proposal_deposit(
+    deps: Deps,
+    proposal_deposit: ProposalDeposit,
+) -> Result<ProposalDeposit, ContractError> {
+    let denom = match proposal_deposit.denom {
+        DepositDenom::Native { denom } => DepositDenom::Native { denom },
+        DepositDenom::Cw20 { address } => DepositDenom::Cw20 {
+            address: deps.api.addr_validate(address.as_str())?,
+        },
+    };
+
+    Ok(ProposalDeposit {
+        denom,
+        ..proposal_deposit
+    })
+}
+
+/// 마감기한 검사 (Never 불가, 최대 등록 기간과 같은 단위)
+fn validate_expiration(
+    env: &Env,
+    config: &Config,
+    expiration: &Expiration,
+    voting_starts_at: &Option<Scheduled>,
+) -> Result<(), ContractError> {
+    if matches!(expiration, Expiration::Never {}) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+    if expiration.is_expired(&env.block) {
+        return Err(ContractError::LowerTime {});
+    }
+    // 마감기한과 같은 단위의 최대 등록 기간으로 검사
+    let max_expiration = [Some(config.max_duration), config.max_duration_secondary]
+        .into_iter()
+        .flatten()
+        .map(|max_duration| max_duration.after(&env.block))
+        .find(|max_expiration| expiration.partial_cmp(max_expiration).is_some())
+        .ok_or(ContractError::ExpirationUnitMismatch {})?;
+    if expiration.partial_cmp(&max_expiration) == Some(Ordering::Greater) {
+        return Err(ContractError::MaxDuration {});
+    }
+
+    // 투표 시작과 마감기한은 같은 단위여야 비교 가능
+    let starts_after_expiration = match (voting_starts_at, expiration) {
+        (None, _) => false,
+        (Some(Scheduled::AtHeight(start)), Expiration::AtHeight(end)) => start >= end,
+        (Some(Scheduled::AtTime(start)), Expiration::AtTime(end)) => start >= end,
+        _ => return Err(ContractError::ExpirationUnitMismatch {}),
+    };
+    if starts_after_expiration {
+        return Err(ContractError::InvalidVotingStart {});
+    }
+
+    Ok(())
+}
+
+/// 보조 최대 등록 기간은 max_duration 과 다른 단위
+fn validate_max_durations(
+    max_duration: &Duration,
+    max_duration_secondary: &Option<Duration>,
+) -> Result<(), ContractError> {
+    let same_unit = matches!(
+        (max_duration, max_duration_secondary),
+        (Duration::Height(_), Some(Duration::Height(_)))
+            | (Duration::Time(_), Some(Duration::Time(_)))
+    );
+    if same_unit {
+        return Err(ContractError::ExpirationUnitMismatch {});
+    }
+
+    Ok(())
+}
+
+/// 정족수, 통과 기준 비율 검사
+fn validate_voting_rules(
+    quorum: &Option<Quorum>,
+    threshold: &Option<Decimal>,
+) -> Result<(), ContractError> {
+    if let Some(Quorum::Percentage { percentage }) = quorum {
+        if *percentage > Decimal::one() {
+            return Err(ContractError::InvalidPercentage {});
+        }
+    }
+    if let Some(threshold) = threshold {
+        if *threshold >= Decimal::one() {
+            return Err(ContractError::InvalidPercentage {});
+        }
+    }
+
+    Ok(())
+}
+
+/// 찬반 투표의 거부 기준은 1 이하
+fn validate_voting_strategy(voting_strategy: &VotingStrategy) -> Result<(), ContractError> {
+    if let VotingStrategy::SingleChoice {
+        veto_threshold: Some(veto_threshold),
+    } = voting_strategy
+    {
+        if *veto_threshold > Decimal::one() {
+            return Err(ContractError::InvalidPercentage {});
+        }
+    }
+
+    Ok(())
+}
+
+/// 찬반 투표는 고정 선택지, 입력한 후보의 메시지는 Yes 통과 시 실행
+fn propsal_candidates(
+    voting_strategy: &VotingStrategy,
+    candidates: Vec<Candidate>,
+) -> Vec<Candidate> {
+    match voting_strategy {
+        VotingStrategy::SingleChoice { .. } => VoteOption::ALL
+            .iter()
+            .map(|option| Candidate {
+                id: option.candidate_id(),
+                name: format!("{:?}", option),
+                msgs: match option {
+                    VoteOption::Yes => candidates.iter().flat_map(|c| c.msgs.clone()).collect(),
+                    _ => vec![],
+                },
+            })
+            .collect(),
+        _ => candidates,
+    }
+}
+
+/// 당선자 수는 1 이상 후보 수 이하, 순위 투표 (instant-runoff), 찬반 투표는 한 명만 선출
+fn validate_seats(
+    voting_strategy: &VotingStrategy,
+    seats: u32,
+    candidates: &[Candidate],
+) -> Result<(), ContractError> {
+    if seats == 0
+        || seats as usize > candidates.len().max(1)
+        || (seats > 1
+            && matches!(
+                voting_strategy,
+                VotingStrategy::RankedChoice | VotingStrategy::SingleChoice { .. }
+            ))
+    {
+        return Err(ContractError::InvalidSeats { seats });
+    }
+
+    Ok(())
+}
+
+/// 투표 시작 전이면 Enabled 대신 Pending
+fn pending_status(
+    status: PropsalStatus,
+    voting_starts_at: &Option<Scheduled>,
+    env: &Env,
+) -> PropsalStatus {
+    match (status, voting_starts_at) {
+        (PropsalStatus::Enabled, Some(voting_starts_at))
+            if !voting_starts_at.is_triggered(&env.block) =>
+        {
+            PropsalStatus::Pending
+        }
+        (status, _) => status,
+    }
+}
+
+/// 제안 추가/수정 시 지정 가능한 상태 체크
+fn only_open_status(status: &PropsalStatus) -> Result<(), ContractError> {
+    match status {
+        PropsalStatus::Enabled | PropsalStatus::Disabled => Ok(()),
+        _ => Err(ContractError::InvalidStatus {
+            status: format!("{:?}", status),
+        }),
+    }
+}
+
+/// 거버넌스 nft 보유자 체크
+fn only_nft_holder(
+    deps: Deps,
+    governing_nft_address: &Option<Addr>,
+    nft_address: &Addr,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    // 보유자는 거버넌스 nft 로 투표하는 제안만 등록 가능
+    if governing_nft_address.as_ref() != Some(nft_address) {
         return Err(ContractError::Unauthorized {});
     }
+    if STAKED_POWER
+        .may_load(deps.storage, sender)?
+        .unwrap_or_default()
+        > 0
+    {
+        return Ok(());
+    }
+    let res: TokensResponse = deps.querier.query_wasm_smart(
+        nft_address,
+        &Cw721QueryMsg::Tokens {
+            owner: sender.to_string(),
+            start_after: None,
+            limit: Some(1),
+        },
+    )?;
+    if res.tokens.is_empty() {
+        return Err(ContractError::NotNftHolder {});
+    }
+
+    Ok(())
+}
+
+/// NFT 소유자 체크
+fn only_token_owner(
+    deps: Deps,
+    nft_address: &Addr,
+    token_id: &str,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    let res: OwnerOfResponse = deps.querier.query_wasm_smart(
+        nft_address,
+        &Cw721QueryMsg::OwnerOf {
+            token_id: token_id.to_string(),
+            include_expired: None,
+        },
+    )?;
+    if res.owner != *sender {
+        return Err(ContractError::NotTokenOwner {
+            token_id: token_id.to_string(),
+        });
+    }
 
     Ok(())
 }
@@ -1,7 +1,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use cosmwasm_std::{to_binary, Addr, CosmosMsg, DepsMut, Env, StdError, StdResult, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, DepsMut, Env, StdResult, WasmMsg};
 
 use crate::{
     msg::ExecuteMsg,
@@ -12,13 +12,14 @@ use crate::{
 pub struct DaoContract(pub Addr);
 
 impl DaoContract {
+    /// 상태가 바뀐 블록 높이 기록, 같은 블록에서는 한 번만 기록
     pub fn increase_block(deps: DepsMut, env: &Env) -> StdResult<()> {
         let idx = BLOCK_INDEX.load(deps.storage)?;
         let block_height = env.block.height;
         let prev_idx = idx - 1;
         let prev_block_height = BLOCK_HEIGHTS.load(deps.storage, prev_idx)?;
         if prev_block_height >= block_height {
-            return Err(StdError::generic_err("block height is not increased"));
+            return Ok(());
         }
         BLOCK_HEIGHTS.save(deps.storage, idx, &block_height)?;
         BLOCK_INDEX.save(deps.storage, &(idx + 1))?;
@@ -31,7 +32,7 @@ impl DaoContract {
     }
 
     pub fn call<T: Into<ExecuteMsg>>(&self, msg: T) -> StdResult<CosmosMsg> {
-        let msg = to_binary(&msg.into())?;
+        let msg = to_json_binary(&msg.into())?;
         Ok(WasmMsg::Execute {
             contract_addr: self.addr().into(),
             msg,
@@ -0,0 +1,1440 @@
+#![cfg(test)]
+
+use cosmwasm_std::{coins, Addr, BankMsg, Binary, CosmosMsg, Decimal, Empty, Uint128};
+use cw721_base::Extension;
+use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor};
+use cw_utils::{Duration, Expiration, PaymentError};
+
+use crate::msg::{
+    ConfigField, DelegatedPowerResponse, DelegationsResponse, ExecuteMsg, InstantiateMsg,
+    PendingClaimsResponse, PropsalResponse, QueryMsg, StakedTokensResponse, TotalStakedResponse,
+    VoteResponse, VotesResponse,
+};
+use crate::state::{
+    Candidate, CreditAllocation, DepositDenom, DepositRefundPolicy, DepositStatus, NftClaim,
+    PowerSource, ProposalDeposit, PropsalStatus, Quorum, VoteOption, VotingStrategy,
+};
+use crate::ContractError;
+
+const OWNER: &str = "owner";
+const HOLDER: &str = "holder";
+const STRANGER: &str = "stranger";
+const GUARDIAN: &str = "guardian";
+const DENOM: &str = "ujuno";
+
+fn dao_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::execute::execute,
+        crate::execute::instantiate,
+        crate::query::query,
+    )
+    .with_reply(crate::execute::reply);
+    Box::new(contract)
+}
+
+fn mock_app() -> App {
+    App::new(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &Addr::unchecked(OWNER), coins(1000, DENOM))
+            .unwrap();
+        router
+            .bank
+            .init_balance(storage, &Addr::unchecked(HOLDER), coins(1000, DENOM))
+            .unwrap();
+    })
+}
+
+fn cw721_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw721_base::entry::execute,
+        cw721_base::entry::instantiate,
+        cw721_base::entry::query,
+    );
+    Box::new(contract)
+}
+
+/// dao, nft 컨트랙트 배포 후 HOLDER 에게 nft 1, 2 발행
+fn setup(app: &mut App) -> (Addr, Addr) {
+    setup_with_msgs(app, vec![])
+}
+
+/// 후보 1 이 통과 시 msgs 를 실행하는 제안 등록, dao 에 1000 ujuno 입금
+fn setup_with_msgs(app: &mut App, msgs: Vec<CosmosMsg>) -> (Addr, Addr) {
+    let cw721_id = app.store_code(cw721_contract());
+    let nft = app
+        .instantiate_contract(
+            cw721_id,
+            Addr::unchecked(OWNER),
+            &cw721_base::InstantiateMsg {
+                name: "dao-nft".to_string(),
+                symbol: "DAO".to_string(),
+                minter: OWNER.to_string(),
+            },
+            &[],
+            "cw721",
+            None,
+        )
+        .unwrap();
+    for token_id in ["1", "2"] {
+        app.execute_contract(
+            Addr::unchecked(OWNER),
+            nft.clone(),
+            &cw721_base::ExecuteMsg::<Extension, Empty>::Mint {
+                token_id: token_id.to_string(),
+                owner: HOLDER.to_string(),
+                token_uri: None,
+                extension: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let dao_id = app.store_code(dao_contract());
+    let dao = app
+        .instantiate_contract(
+            dao_id,
+            Addr::unchecked(OWNER),
+            &InstantiateMsg {
+                owner: OWNER.to_string(),
+                max_duration: Duration::Time(2629743),
+                max_duration_secondary: None,
+                quorum: None,
+                threshold: None,
+                timelock_seconds: 100,
+                guardians: vec![GUARDIAN.to_string()],
+                nft_address: Some(nft.to_string()),
+                unbonding_period: None,
+                voting_strategy: None,
+                proposal_deposit: Some(ProposalDeposit {
+                    denom: DepositDenom::Native {
+                        denom: DENOM.to_string(),
+                    },
+                    amount: Uint128::new(100),
+                    refund_policy: DepositRefundPolicy::QuorumMet,
+                }),
+            },
+            &[],
+            "dao",
+            None,
+        )
+        .unwrap();
+    app.send_tokens(Addr::unchecked(OWNER), dao.clone(), &coins(1000, DENOM))
+        .unwrap();
+
+    app.update_block(next_block);
+    let expiration = Expiration::AtTime(app.block_info().time.plus_seconds(100));
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        dao.clone(),
+        &ExecuteMsg::AddPropsal {
+            title: "test-propsal".to_string(),
+            status: PropsalStatus::Enabled,
+            nft_address: nft.to_string(),
+            expiration,
+            candidates: vec![Candidate {
+                id: 1,
+                name: "candidate1".to_string(),
+                msgs,
+            }],
+            allow_revoting: false,
+            tie_breaker: None,
+            voting_starts_at: None,
+            quorum: None,
+            threshold: None,
+            // 관리자는 투표 시 제출한 토큰 기준으로 지정 가능
+            power_source: Some(PowerSource::TokenIds),
+            voting_strategy: None,
+            seats: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    (dao, nft)
+}
+
+fn vote(candidate_id: u64, token_ids: &[&str]) -> ExecuteMsg {
+    ExecuteMsg::ExecuteVote {
+        propsal_id: 1,
+        candidate_id,
+        token_ids: token_ids
+            .iter()
+            .map(|token_id| token_id.to_string())
+            .collect(),
+    }
+}
+
+#[test]
+fn test_vote_requires_nft() {
+    let mut app = mock_app();
+    let (dao, _) = setup(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(STRANGER),
+            dao.clone(),
+            &vote(1, &["1"]),
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::NotTokenOwner { .. })
+    ));
+
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &vote(1, &["1", "2"]),
+        &[],
+    )
+    .unwrap();
+
+    let res: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            dao,
+            &QueryMsg::Vote {
+                propsal_id: 1,
+                voter: HOLDER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(2, res.vote.unwrap().power);
+}
+
+#[test]
+fn test_transferred_token_cannot_vote_again() {
+    let mut app = mock_app();
+    let (dao, nft) = setup(&mut app);
+
+    app.execute_contract(Addr::unchecked(HOLDER), dao.clone(), &vote(1, &["1"]), &[])
+        .unwrap();
+    app.update_block(next_block);
+
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        nft,
+        &cw721_base::ExecuteMsg::<Extension, Empty>::TransferNft {
+            recipient: STRANGER.to_string(),
+            token_id: "1".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    let err = app
+        .execute_contract(Addr::unchecked(STRANGER), dao, &vote(1, &["1"]), &[])
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::NoVotingPower {})
+    ));
+}
+
+/// 투표 마감 후 결과 확정
+fn finalize(app: &mut App, dao: &Addr) {
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(100);
+    });
+    app.execute_contract(
+        Addr::unchecked(STRANGER),
+        dao.clone(),
+        &ExecuteMsg::Finalize { id: 1 },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+}
+
+/// 타임락 대기
+fn wait_timelock(app: &mut App) {
+    app.update_block(|block| {
+        block.height += 1;
+        block.time = block.time.plus_seconds(100);
+    });
+}
+
+fn propsal_status(app: &App, dao: &Addr) -> PropsalStatus {
+    let res: PropsalResponse = app
+        .wrap()
+        .query_wasm_smart(dao, &QueryMsg::Propsal { id: 1 })
+        .unwrap();
+    res.propsal.status
+}
+
+#[test]
+fn test_execute_proposal() {
+    let mut app = mock_app();
+    let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: STRANGER.to_string(),
+        amount: coins(100, DENOM),
+    })];
+    let (dao, _) = setup_with_msgs(&mut app, msgs);
+
+    // 확정 전에는 실행 불가
+    let execute_proposal = ExecuteMsg::ExecuteProposal { id: 1 };
+    app.execute_contract(Addr::unchecked(HOLDER), dao.clone(), &vote(1, &["1"]), &[])
+        .unwrap();
+    let err = app
+        .execute_contract(
+            Addr::unchecked(STRANGER),
+            dao.clone(),
+            &execute_proposal,
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::PropsalNotPassed {})
+    ));
+
+    finalize(&mut app, &dao);
+    assert_eq!(PropsalStatus::Queued, propsal_status(&app, &dao));
+
+    // 타임락 전에는 실행 불가
+    let err = app
+        .execute_contract(
+            Addr::unchecked(STRANGER),
+            dao.clone(),
+            &execute_proposal,
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::Timelocked { .. })
+    ));
+
+    wait_timelock(&mut app);
+    app.execute_contract(
+        Addr::unchecked(STRANGER),
+        dao.clone(),
+        &execute_proposal,
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(PropsalStatus::Executed, propsal_status(&app, &dao));
+    let balance = app.wrap().query_balance(STRANGER, DENOM).unwrap();
+    assert_eq!(100u128, balance.amount.u128());
+
+    // 중복 실행 불가
+    app.update_block(next_block);
+    let err = app
+        .execute_contract(
+            Addr::unchecked(STRANGER),
+            dao.clone(),
+            &execute_proposal,
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::AlreadyExecuted {})
+    ));
+}
+
+#[test]
+fn test_execute_proposal_failed() {
+    let mut app = mock_app();
+    // 앞의 메시지가 성공해도 하나라도 실패하면 모두 취소
+    let msgs = vec![
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: STRANGER.to_string(),
+            amount: coins(100, DENOM),
+        }),
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: STRANGER.to_string(),
+            amount: coins(5000, DENOM),
+        }),
+    ];
+    let (dao, _) = setup_with_msgs(&mut app, msgs);
+
+    app.execute_contract(Addr::unchecked(HOLDER), dao.clone(), &vote(1, &["1"]), &[])
+        .unwrap();
+    finalize(&mut app, &dao);
+    wait_timelock(&mut app);
+    app.execute_contract(
+        Addr::unchecked(STRANGER),
+        dao.clone(),
+        &ExecuteMsg::ExecuteProposal { id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(PropsalStatus::ExecutionFailed, propsal_status(&app, &dao));
+    let balance = app.wrap().query_balance(STRANGER, DENOM).unwrap();
+    assert_eq!(0u128, balance.amount.u128());
+    let balance = app.wrap().query_balance(&dao, DENOM).unwrap();
+    assert_eq!(1000u128, balance.amount.u128());
+
+    // 메시지 일괄 실행은 컨트랙트 자신만 호출 가능
+    let err = app
+        .execute_contract(
+            Addr::unchecked(STRANGER),
+            dao,
+            &ExecuteMsg::ExecutePropsalMsgs { id: 1 },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::Unauthorized {})
+    ));
+}
+
+#[test]
+fn test_cancel_proposal() {
+    let mut app = mock_app();
+    let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: STRANGER.to_string(),
+        amount: coins(100, DENOM),
+    })];
+    let (dao, _) = setup_with_msgs(&mut app, msgs);
+
+    // 대기 중이 아닌 제안은 취소 불가
+    let cancel_proposal = ExecuteMsg::CancelProposal { id: 1 };
+    let err = app
+        .execute_contract(
+            Addr::unchecked(GUARDIAN),
+            dao.clone(),
+            &cancel_proposal,
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::PropsalNotQueued {})
+    ));
+
+    app.execute_contract(Addr::unchecked(HOLDER), dao.clone(), &vote(1, &["1"]), &[])
+        .unwrap();
+    finalize(&mut app, &dao);
+
+    // 오너, 가디언만 취소 가능
+    let err = app
+        .execute_contract(
+            Addr::unchecked(STRANGER),
+            dao.clone(),
+            &cancel_proposal,
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::Unauthorized {})
+    ));
+    app.execute_contract(
+        Addr::unchecked(GUARDIAN),
+        dao.clone(),
+        &cancel_proposal,
+        &[],
+    )
+    .unwrap();
+    assert_eq!(PropsalStatus::Cancelled, propsal_status(&app, &dao));
+
+    wait_timelock(&mut app);
+    let err = app
+        .execute_contract(
+            Addr::unchecked(STRANGER),
+            dao.clone(),
+            &ExecuteMsg::ExecuteProposal { id: 1 },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::PropsalCancelled {})
+    ));
+    let balance = app.wrap().query_balance(dao, DENOM).unwrap();
+    assert_eq!(1000u128, balance.amount.u128());
+}
+
+#[test]
+fn test_veto() {
+    let mut app = mock_app();
+    let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: STRANGER.to_string(),
+        amount: coins(100, DENOM),
+    })];
+    let (dao, _) = setup_with_msgs(&mut app, msgs);
+
+    // 가디언만 거부권 행사 가능
+    let veto = ExecuteMsg::Veto {
+        id: 1,
+        reason: "malicious".to_string(),
+    };
+    for sender in [OWNER, STRANGER] {
+        let err = app
+            .execute_contract(Addr::unchecked(sender), dao.clone(), &veto, &[])
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ContractError>(),
+            Some(ContractError::Unauthorized {})
+        ));
+    }
+
+    app.execute_contract(Addr::unchecked(HOLDER), dao.clone(), &vote(1, &["1"]), &[])
+        .unwrap();
+    finalize(&mut app, &dao);
+    let res = app
+        .execute_contract(Addr::unchecked(GUARDIAN), dao.clone(), &veto, &[])
+        .unwrap();
+    let event = res
+        .events
+        .iter()
+        .find(|event| event.ty == "wasm-propsal_vetoed")
+        .unwrap();
+    assert!(event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "reason" && attr.value == "malicious"));
+
+    let res: PropsalResponse = app
+        .wrap()
+        .query_wasm_smart(&dao, &QueryMsg::Propsal { id: 1 })
+        .unwrap();
+    assert_eq!(PropsalStatus::Vetoed, res.propsal.status);
+    assert_eq!(Some("malicious".to_string()), res.propsal.veto_reason);
+
+    wait_timelock(&mut app);
+    let err = app
+        .execute_contract(
+            Addr::unchecked(STRANGER),
+            dao.clone(),
+            &ExecuteMsg::ExecuteProposal { id: 1 },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::PropsalNotPassed {})
+    ));
+}
+
+#[test]
+fn test_veto_after_execution() {
+    let mut app = mock_app();
+    let msgs = vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: STRANGER.to_string(),
+        amount: coins(100, DENOM),
+    })];
+    let (dao, _) = setup_with_msgs(&mut app, msgs);
+
+    app.execute_contract(Addr::unchecked(HOLDER), dao.clone(), &vote(1, &["1"]), &[])
+        .unwrap();
+    finalize(&mut app, &dao);
+    wait_timelock(&mut app);
+    app.execute_contract(
+        Addr::unchecked(STRANGER),
+        dao.clone(),
+        &ExecuteMsg::ExecuteProposal { id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(next_block);
+    let err = app
+        .execute_contract(
+            Addr::unchecked(GUARDIAN),
+            dao.clone(),
+            &ExecuteMsg::Veto {
+                id: 1,
+                reason: "too late".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::AlreadyExecuted {})
+    ));
+}
+
+/// nft 보유자가 등록하는 제안
+fn holder_propsal(app: &App, nft_address: &Addr) -> ExecuteMsg {
+    let expiration = Expiration::AtTime(app.block_info().time.plus_seconds(100));
+    ExecuteMsg::AddPropsal {
+        title: "holder-propsal".to_string(),
+        status: PropsalStatus::Enabled,
+        nft_address: nft_address.to_string(),
+        expiration,
+        candidates: vec![Candidate {
+            id: 1,
+            name: "candidate1".to_string(),
+            msgs: vec![],
+        }],
+        allow_revoting: false,
+        tie_breaker: None,
+        voting_starts_at: None,
+        quorum: None,
+        threshold: None,
+        power_source: None,
+        voting_strategy: None,
+        seats: None,
+    }
+}
+
+/// 정족수, 집계 방식만 바꾸는 설정 업데이트
+fn config_update(quorum: Option<Quorum>, voting_strategy: Option<VotingStrategy>) -> ExecuteMsg {
+    ExecuteMsg::UpdateConfig {
+        max_duration: None,
+        max_duration_secondary: None,
+        quorum,
+        threshold: None,
+        timelock_seconds: None,
+        nft_address: None,
+        unbonding_period: None,
+        voting_strategy,
+        proposal_deposit: None,
+    }
+}
+
+fn propsal_deposit_status(app: &App, dao: &Addr, id: u64) -> Option<DepositStatus> {
+    let res: PropsalResponse = app
+        .wrap()
+        .query_wasm_smart(dao, &QueryMsg::Propsal { id })
+        .unwrap();
+    res.propsal.deposit.map(|deposit| deposit.status)
+}
+
+#[test]
+fn test_holder_propsal_deposit() {
+    let mut app = mock_app();
+    let (dao, nft) = setup(&mut app);
+
+    // nft 보유자만 제안 가능
+    let err = app
+        .execute_contract(
+            Addr::unchecked(STRANGER),
+            dao.clone(),
+            &holder_propsal(&app, &nft),
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::NotNftHolder {})
+    ));
+    let err = app
+        .execute_contract(
+            Addr::unchecked(HOLDER),
+            dao.clone(),
+            &holder_propsal(&app, &dao),
+            &coins(100, DENOM),
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::Unauthorized {})
+    ));
+    let err = app
+        .execute_contract(
+            Addr::unchecked(HOLDER),
+            dao.clone(),
+            &holder_propsal(&app, &nft),
+            &coins(50, DENOM),
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::InvalidDeposit { .. })
+    ));
+
+    // 역할 보유자는 보증금이 없으므로 자금을 보낼 수 없음
+    app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &Addr::unchecked(OWNER), coins(100, DENOM))
+            .unwrap();
+    });
+    let err = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            dao.clone(),
+            &holder_propsal(&app, &nft),
+            &coins(100, DENOM),
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::Payment(PaymentError::NonPayable {}))
+    ));
+
+    // 거버넌스 nft 제안은 등록 시점 스테이킹 power 기준
+    stake(&mut app, &dao, &nft, HOLDER, "1");
+    app.update_block(next_block);
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &holder_propsal(&app, &nft),
+        &coins(100, DENOM),
+    )
+    .unwrap();
+    let res: PropsalResponse = app
+        .wrap()
+        .query_wasm_smart(&dao, &QueryMsg::Propsal { id: 2 })
+        .unwrap();
+    assert_eq!(PowerSource::Staked, res.propsal.power_source);
+    assert_eq!(
+        Some(DepositStatus::Held),
+        propsal_deposit_status(&app, &dao, 2)
+    );
+    let balance = app.wrap().query_balance(HOLDER, DENOM).unwrap();
+    assert_eq!(900u128, balance.amount.u128());
+
+    // 정족수를 채우면 확정 시 반환
+    app.update_block(next_block);
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::ExecuteVote {
+            propsal_id: 2,
+            candidate_id: 1,
+            token_ids: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    wait_timelock(&mut app);
+    app.execute_contract(
+        Addr::unchecked(STRANGER),
+        dao.clone(),
+        &ExecuteMsg::Finalize { id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(
+        Some(DepositStatus::Refunded),
+        propsal_deposit_status(&app, &dao, 2)
+    );
+    let balance = app.wrap().query_balance(HOLDER, DENOM).unwrap();
+    assert_eq!(1000u128, balance.amount.u128());
+
+    // 보증금 설정을 없애면 보증금 없이 제안
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        dao.clone(),
+        &ExecuteMsg::ClearConfig {
+            fields: vec![ConfigField::ProposalDeposit],
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &holder_propsal(&app, &nft),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(None, propsal_deposit_status(&app, &dao, 3));
+}
+
+#[test]
+fn test_holder_propsal_deposit_forfeited() {
+    let mut app = mock_app();
+    let (dao, nft) = setup(&mut app);
+
+    // 정족수 미달
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        dao.clone(),
+        &config_update(Some(Quorum::AbsolutePower { power: 2 }), None),
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &holder_propsal(&app, &nft),
+        &coins(100, DENOM),
+    )
+    .unwrap();
+    wait_timelock(&mut app);
+    app.execute_contract(
+        Addr::unchecked(STRANGER),
+        dao.clone(),
+        &ExecuteMsg::Finalize { id: 2 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        Some(DepositStatus::Forfeited),
+        propsal_deposit_status(&app, &dao, 2)
+    );
+
+    // 거부권 행사
+    app.update_block(next_block);
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &holder_propsal(&app, &nft),
+        &coins(100, DENOM),
+    )
+    .unwrap();
+    app.update_block(next_block);
+    app.execute_contract(
+        Addr::unchecked(GUARDIAN),
+        dao.clone(),
+        &ExecuteMsg::Veto {
+            id: 3,
+            reason: "spam".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        Some(DepositStatus::Forfeited),
+        propsal_deposit_status(&app, &dao, 3)
+    );
+
+    let balance = app.wrap().query_balance(HOLDER, DENOM).unwrap();
+    assert_eq!(800u128, balance.amount.u128());
+    let balance = app.wrap().query_balance(dao, DENOM).unwrap();
+    assert_eq!(1200u128, balance.amount.u128());
+}
+
+#[test]
+fn test_holder_propsal_voting_rules() {
+    let mut app = mock_app();
+    let (dao, nft) = setup(&mut app);
+
+    // 역할 없는 보유자는 정족수, 통과 기준 등 투표 규칙 지정 불가
+    let overrides: Vec<fn(&mut ExecuteMsg)> = vec![
+        |msg| {
+            if let ExecuteMsg::AddPropsal { quorum, .. } = msg {
+                *quorum = Some(Quorum::AbsolutePower { power: 1 });
+            }
+        },
+        |msg| {
+            if let ExecuteMsg::AddPropsal { threshold, .. } = msg {
+                *threshold = Some(Decimal::zero());
+            }
+        },
+        |msg| {
+            if let ExecuteMsg::AddPropsal { power_source, .. } = msg {
+                *power_source = Some(PowerSource::TokenIds);
+            }
+        },
+        |msg| {
+            if let ExecuteMsg::AddPropsal {
+                voting_strategy, ..
+            } = msg
+            {
+                *voting_strategy = Some(VotingStrategy::SingleChoice {
+                    veto_threshold: Some(Decimal::one()),
+                });
+            }
+        },
+    ];
+    for set_override in overrides {
+        let mut msg = holder_propsal(&app, &nft);
+        set_override(&mut msg);
+        let err = app
+            .execute_contract(
+                Addr::unchecked(HOLDER),
+                dao.clone(),
+                &msg,
+                &coins(100, DENOM),
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ContractError>(),
+            Some(ContractError::VotingRulesOverride {})
+        ));
+    }
+
+    // 역할이 있으면 지정 가능
+    let mut msg = holder_propsal(&app, &nft);
+    if let ExecuteMsg::AddPropsal { quorum, .. } = &mut msg {
+        *quorum = Some(Quorum::AbsolutePower { power: 1 });
+    }
+    app.execute_contract(Addr::unchecked(OWNER), dao, &msg, &[])
+        .unwrap();
+}
+
+#[test]
+fn test_single_choice_veto_burns_deposit() {
+    let mut app = mock_app();
+    let (dao, nft) = setup(&mut app);
+
+    // 보유자 제안은 Config 기본 집계 방식 사용
+    let single_choice = VotingStrategy::SingleChoice {
+        veto_threshold: None,
+    };
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        dao.clone(),
+        &config_update(None, Some(single_choice)),
+        &[],
+    )
+    .unwrap();
+    stake(&mut app, &dao, &nft, HOLDER, "1");
+    app.update_block(next_block);
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &holder_propsal(&app, &nft),
+        &coins(100, DENOM),
+    )
+    .unwrap();
+    app.update_block(next_block);
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::ExecuteSingleChoiceVote {
+            propsal_id: 2,
+            option: VoteOption::NoWithVeto,
+            token_ids: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    wait_timelock(&mut app);
+    app.execute_contract(
+        Addr::unchecked(STRANGER),
+        dao.clone(),
+        &ExecuteMsg::Finalize { id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    // 반환 정책과 관계없이 소각
+    assert_eq!(
+        Some(DepositStatus::Burned),
+        propsal_deposit_status(&app, &dao, 2)
+    );
+    let balance = app.wrap().query_balance(HOLDER, DENOM).unwrap();
+    assert_eq!(900u128, balance.amount.u128());
+    let balance = app.wrap().query_balance(dao, DENOM).unwrap();
+    assert_eq!(1000u128, balance.amount.u128());
+}
+
+fn stake(app: &mut App, dao: &Addr, nft: &Addr, sender: &str, token_id: &str) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        nft.clone(),
+        &cw721_base::ExecuteMsg::<Extension, Empty>::SendNft {
+            contract: dao.to_string(),
+            token_id: token_id.to_string(),
+            msg: Binary::default(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_staked_power_snapshot() {
+    let mut app = mock_app();
+    let (dao, nft) = setup(&mut app);
+
+    stake(&mut app, &dao, &nft, HOLDER, "1");
+    app.update_block(next_block);
+
+    let mut msg = holder_propsal(&app, &nft);
+    if let ExecuteMsg::AddPropsal { power_source, .. } = &mut msg {
+        *power_source = Some(PowerSource::Staked);
+    }
+    app.execute_contract(Addr::unchecked(OWNER), dao.clone(), &msg, &[])
+        .unwrap();
+    // 등록 이후 스테이킹은 power 에 포함되지 않음
+    stake(&mut app, &dao, &nft, HOLDER, "2");
+    app.update_block(next_block);
+
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::ExecuteVote {
+            propsal_id: 2,
+            candidate_id: 1,
+            token_ids: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    let res: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &dao,
+            &QueryMsg::Vote {
+                propsal_id: 2,
+                voter: HOLDER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(1, res.vote.unwrap().power);
+
+    // 스테이킹 해제 시 nft 반환
+    let err = app
+        .execute_contract(
+            Addr::unchecked(STRANGER),
+            dao.clone(),
+            &ExecuteMsg::Unstake {
+                token_ids: vec!["1".to_string()],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::NotStaked { .. })
+    ));
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::Unstake {
+            token_ids: vec!["1".to_string(), "2".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    let res: cw721::OwnerOfResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &nft,
+            &cw721::Cw721QueryMsg::OwnerOf {
+                token_id: "2".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(HOLDER, res.owner);
+}
+
+#[test]
+fn test_unstake_unbonding() {
+    let mut app = mock_app();
+    let (dao, nft) = setup(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        dao.clone(),
+        &ExecuteMsg::UpdateConfig {
+            max_duration: None,
+            max_duration_secondary: None,
+            quorum: None,
+            threshold: None,
+            timelock_seconds: None,
+            nft_address: None,
+            unbonding_period: Some(Duration::Height(10)),
+            voting_strategy: None,
+            proposal_deposit: None,
+        },
+        &[],
+    )
+    .unwrap();
+    stake(&mut app, &dao, &nft, HOLDER, "1");
+    stake(&mut app, &dao, &nft, HOLDER, "2");
+
+    // 스테이킹 중에는 거버넌스 nft 변경 불가
+    let update_nft = ExecuteMsg::UpdateConfig {
+        max_duration: None,
+        max_duration_secondary: None,
+        quorum: None,
+        threshold: None,
+        timelock_seconds: None,
+        nft_address: Some(STRANGER.to_string()),
+        unbonding_period: None,
+        voting_strategy: None,
+        proposal_deposit: None,
+    };
+    let err = app
+        .execute_contract(Addr::unchecked(OWNER), dao.clone(), &update_nft, &[])
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::NftStaked {})
+    ));
+
+    let res: StakedTokensResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &dao,
+            &QueryMsg::StakedTokens {
+                address: HOLDER.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(vec!["1".to_string(), "2".to_string()], res.token_ids);
+    assert_eq!(2, res.power);
+
+    app.update_block(next_block);
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::Unstake {
+            token_ids: vec!["1".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    let res: TotalStakedResponse = app
+        .wrap()
+        .query_wasm_smart(&dao, &QueryMsg::TotalStaked {})
+        .unwrap();
+    assert_eq!(1, res.total);
+    let res: PendingClaimsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &dao,
+            &QueryMsg::PendingClaims {
+                address: HOLDER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        vec![NftClaim {
+            token_id: "1".to_string(),
+            release_at: Expiration::AtHeight(app.block_info().height + 10),
+        }],
+        res.claims
+    );
+
+    // unbonding 기간 전에는 반환 불가
+    let err = app
+        .execute_contract(
+            Addr::unchecked(HOLDER),
+            dao.clone(),
+            &ExecuteMsg::ClaimNfts {},
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::NothingToClaim {})
+    ));
+
+    app.update_block(|block| block.height += 10);
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::ClaimNfts {},
+        &[],
+    )
+    .unwrap();
+    let res: cw721::OwnerOfResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &nft,
+            &cw721::Cw721QueryMsg::OwnerOf {
+                token_id: "1".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(HOLDER, res.owner);
+    let res: PendingClaimsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &dao,
+            &QueryMsg::PendingClaims {
+                address: HOLDER.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.claims.is_empty());
+
+    // 반환 대기 중인 nft 가 남아 있어도 변경 불가, 모두 반환되면 변경 가능
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::Unstake {
+            token_ids: vec!["2".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    let err = app
+        .execute_contract(Addr::unchecked(OWNER), dao.clone(), &update_nft, &[])
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::NftStaked {})
+    ));
+    app.update_block(|block| block.height += 10);
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::ClaimNfts {},
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(Addr::unchecked(OWNER), dao, &update_nft, &[])
+        .unwrap();
+}
+
+fn staked_vote_power(app: &App, dao: &Addr, voter: &str) -> u64 {
+    let res: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            dao,
+            &QueryMsg::Vote {
+                propsal_id: 2,
+                voter: voter.to_string(),
+            },
+        )
+        .unwrap();
+    res.vote.unwrap().power
+}
+
+#[test]
+fn test_delegate_override() {
+    let mut app = mock_app();
+    let (dao, nft) = setup(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        nft.clone(),
+        &cw721_base::ExecuteMsg::<Extension, Empty>::Mint {
+            token_id: "3".to_string(),
+            owner: STRANGER.to_string(),
+            token_uri: None,
+            extension: None,
+        },
+        &[],
+    )
+    .unwrap();
+    stake(&mut app, &dao, &nft, HOLDER, "1");
+    stake(&mut app, &dao, &nft, HOLDER, "2");
+    stake(&mut app, &dao, &nft, STRANGER, "3");
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::Delegate {
+            to: STRANGER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res: DelegatedPowerResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &dao,
+            &QueryMsg::DelegatedPower {
+                address: STRANGER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(2, res.power);
+    let res: DelegationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &dao,
+            &QueryMsg::Delegations {
+                delegate: STRANGER.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(vec![Addr::unchecked(HOLDER)], res.delegators);
+
+    app.update_block(next_block);
+    let mut msg = holder_propsal(&app, &nft);
+    if let ExecuteMsg::AddPropsal { power_source, .. } = &mut msg {
+        *power_source = Some(PowerSource::Staked);
+    }
+    app.execute_contract(Addr::unchecked(OWNER), dao.clone(), &msg, &[])
+        .unwrap();
+    app.update_block(next_block);
+
+    // 대리인은 자신의 power 와 위임받은 power 로 투표
+    let vote_msg = ExecuteMsg::ExecuteVote {
+        propsal_id: 2,
+        candidate_id: 1,
+        token_ids: vec![],
+    };
+    app.execute_contract(Addr::unchecked(STRANGER), dao.clone(), &vote_msg, &[])
+        .unwrap();
+    assert_eq!(3, staked_vote_power(&app, &dao, STRANGER));
+
+    // 위임자가 직접 투표하면 이 제안에서는 대리인 투표에서 제외
+    app.execute_contract(Addr::unchecked(HOLDER), dao.clone(), &vote_msg, &[])
+        .unwrap();
+    assert_eq!(2, staked_vote_power(&app, &dao, HOLDER));
+    assert_eq!(1, staked_vote_power(&app, &dao, STRANGER));
+    let err = app
+        .execute_contract(Addr::unchecked(HOLDER), dao.clone(), &vote_msg, &[])
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::AlreadyVoted {})
+    ));
+    let res: PropsalResponse = app
+        .wrap()
+        .query_wasm_smart(&dao, &QueryMsg::Propsal { id: 2 })
+        .unwrap();
+    assert_eq!(3, res.propsal.total_power);
+
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::Undelegate {},
+        &[],
+    )
+    .unwrap();
+    let res: DelegatedPowerResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &dao,
+            &QueryMsg::DelegatedPower {
+                address: STRANGER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(0, res.power);
+}
+
+#[test]
+fn test_delegate_override_quadratic() {
+    let mut app = mock_app();
+    let (dao, nft) = setup(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        nft.clone(),
+        &cw721_base::ExecuteMsg::<Extension, Empty>::Mint {
+            token_id: "3".to_string(),
+            owner: STRANGER.to_string(),
+            token_uri: None,
+            extension: None,
+        },
+        &[],
+    )
+    .unwrap();
+    stake(&mut app, &dao, &nft, HOLDER, "1");
+    stake(&mut app, &dao, &nft, HOLDER, "2");
+    stake(&mut app, &dao, &nft, STRANGER, "3");
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::Delegate {
+            to: STRANGER.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(next_block);
+    let mut msg = holder_propsal(&app, &nft);
+    if let ExecuteMsg::AddPropsal {
+        candidates,
+        voting_strategy,
+        ..
+    } = &mut msg
+    {
+        candidates.push(Candidate {
+            id: 2,
+            name: "candidate2".to_string(),
+            msgs: vec![],
+        });
+        *voting_strategy = Some(VotingStrategy::Quadratic);
+    }
+    app.execute_contract(Addr::unchecked(OWNER), dao.clone(), &msg, &[])
+        .unwrap();
+    app.update_block(next_block);
+
+    // 대리인이 위임받은 power 로 두 후보에 credit 배분
+    app.execute_contract(
+        Addr::unchecked(STRANGER),
+        dao.clone(),
+        &ExecuteMsg::ExecuteQuadraticVote {
+            propsal_id: 2,
+            allocations: vec![
+                CreditAllocation {
+                    candidate_id: 1,
+                    credits: 1,
+                },
+                CreditAllocation {
+                    candidate_id: 2,
+                    credits: 2,
+                },
+            ],
+            token_ids: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    // 위임자가 직접 투표하면 대리인의 줄어든 credit 에서 빠진 후보의 투표 목록에서도 제외
+    app.execute_contract(
+        Addr::unchecked(HOLDER),
+        dao.clone(),
+        &ExecuteMsg::ExecuteQuadraticVote {
+            propsal_id: 2,
+            allocations: vec![CreditAllocation {
+                candidate_id: 1,
+                credits: 2,
+            }],
+            token_ids: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(1, staked_vote_power(&app, &dao, STRANGER));
+    let candidate_voters = |candidate_id: u64| -> Vec<Addr> {
+        let res: VotesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &dao,
+                &QueryMsg::CandidateVotes {
+                    propsal_id: 2,
+                    candidate_id,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        res.votes.into_iter().map(|vote| vote.voter).collect()
+    };
+    assert_eq!(
+        vec![Addr::unchecked(HOLDER), Addr::unchecked(STRANGER)],
+        candidate_voters(1)
+    );
+    assert!(candidate_voters(2).is_empty());
+}
+
+#[test]
+fn test_update_staked_propsal_nft() {
+    let mut app = mock_app();
+    let (dao, nft) = setup(&mut app);
+
+    // 거버넌스 nft 제안은 기본적으로 스테이킹 power 사용, 다른 nft 로 변경 불가
+    let msg = holder_propsal(&app, &nft);
+    app.execute_contract(Addr::unchecked(OWNER), dao.clone(), &msg, &[])
+        .unwrap();
+    let update_nft = |nft_address: &str| ExecuteMsg::UpdatePropsal {
+        id: 2,
+        title: None,
+        status: None,
+        nft_address: Some(nft_address.to_string()),
+        expiration: None,
+        candidates: None,
+    };
+    let err = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            dao.clone(),
+            &update_nft(STRANGER),
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<ContractError>(),
+        Some(ContractError::InvalidStakingNft {})
+    ));
+    app.execute_contract(Addr::unchecked(OWNER), dao, &update_nft(nft.as_str()), &[])
+        .unwrap();
+}
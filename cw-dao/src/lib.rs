@@ -1,11 +1,19 @@
+mod auth;
+mod deposit;
 mod error;
 pub mod execute;
 pub mod helpers;
+mod migrations;
 pub mod msg;
+mod power;
 pub mod query;
+mod runoff;
 pub mod state;
+pub mod tally;
 
 pub use crate::error::ContractError;
 
 #[cfg(test)]
 mod contract_tests;
+#[cfg(test)]
+mod integration_tests;
@@ -0,0 +1,183 @@
+use cosmwasm_schema::cw_serde;
+
+use cosmwasm_std::{Addr, DepsMut, Empty, Order::Ascending, StdResult, Timestamp};
+use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
+
+use crate::error::ContractError;
+use crate::msg::RecoverPropsal;
+use crate::state::{
+    votes, Candidate, Config, Ownership, PowerSource, Propsal, PropsalStatus, Vote, VotingStrategy,
+    CANDIDATE_VOTERS, CONFIG, OWNERSHIP, PROPSALS, PROPSAL_COUNT, PROPSAL_INDEX,
+};
+use crate::tally::add_tally;
+
+/// 0.1.x 컨트랙트 설정
+#[cw_serde]
+struct LegacyConfig {
+    start_blocknumber: u64,
+    max_duration_seconds: u64,
+    owner: Addr,
+}
+
+/// 0.1.x 투표 제안서 (투표를 제안 안에 저장)
+#[cw_serde]
+struct LegacyPropsal {
+    id: u64,
+    title: String,
+    status: PropsalStatus,
+    nft_address: Addr,
+    expiration: u64,
+    votes: Vec<LegacyVote>,
+    candidates: Vec<Candidate>,
+}
+
+/// 0.1.x 투표
+#[cw_serde]
+struct LegacyVote {
+    power: u64,
+    voter: Addr,
+    propsal_id: u64,
+    candidate_id: u64,
+}
+
+const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+const LEGACY_PROPSALS: Map<u64, LegacyPropsal> = Map::new("propsals");
+
+/// 0.1.x -> 현재 상태 마이그레이션
+///
+/// 0.1.x 는 `PROPSAL_INDEX` 를 증가시키지 않아 모든 제안이 1번을 덮어썼다.
+/// 남아있는 제안의 투표를 투표 저장소로 옮기고, 덮어써진 제안은 이벤트 로그에서
+/// 가져온 `recover_propsals` 로 새 아이디를 받아 다시 등록한다.
+/// 덮어써진 제안의 투표는 복구할 수 없다. 복구된 제안 아이디를 반환.
+pub fn migrate_v0_1(
+    deps: DepsMut,
+    recover_propsals: Vec<RecoverPropsal>,
+) -> Result<Vec<u64>, ContractError> {
+    let legacy_config = LEGACY_CONFIG.load(deps.storage)?;
+    let config = Config {
+        start_blocknumber: legacy_config.start_blocknumber,
+        max_duration: Duration::Time(legacy_config.max_duration_seconds),
+        max_duration_secondary: None,
+        quorum: None,
+        threshold: None,
+        timelock_seconds: 0,
+        nft_address: None,
+        unbonding_period: None,
+        voting_strategy: VotingStrategy::Plurality,
+        proposal_deposit: None,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    OWNERSHIP.save(
+        deps.storage,
+        &Ownership {
+            owner: Some(legacy_config.owner.clone()),
+            pending_owner: None,
+            pending_expiry: None,
+        },
+    )?;
+
+    let legacy_propsals = LEGACY_PROPSALS
+        .range(deps.storage, None, None, Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut next_id = 1u64;
+    let mut count = 0u64;
+    for (id, legacy) in legacy_propsals {
+        let mut propsal = Propsal {
+            id,
+            title: legacy.title,
+            status: legacy.status,
+            nft_address: legacy.nft_address,
+            expiration: Expiration::AtTime(Timestamp::from_seconds(legacy.expiration)),
+            voting_starts_at: None,
+            start_height: 0,
+            power_source: PowerSource::TokenIds,
+            voting_strategy: VotingStrategy::Plurality,
+            seats: 1,
+            candidates: legacy.candidates,
+            allow_revoting: false,
+            tie_breaker: None,
+            quorum: None,
+            threshold: None,
+            total_power: 0,
+            voter_count: 0,
+            vote_count: 0,
+            executable_at: None,
+            veto_reason: None,
+            proposer: legacy_config.owner.clone(),
+            deposit: None,
+        };
+
+        for legacy_vote in legacy.votes {
+            // 같은 투표자의 중복 투표는 첫 투표만 인정
+            if votes()
+                .may_load(deps.storage, (id, &legacy_vote.voter))?
+                .is_some()
+            {
+                continue;
+            }
+            let vote = Vote {
+                power: legacy_vote.power,
+                voter: legacy_vote.voter,
+                propsal_id: id,
+                candidate_id: legacy_vote.candidate_id,
+                token_ids: vec![],
+                credits: vec![],
+                ranking: vec![],
+                approvals: vec![],
+            };
+            propsal.vote_count += 1;
+            add_tally(deps.storage, &propsal, vote.candidate_id, vote.power)?;
+            propsal.total_power += vote.power;
+            propsal.voter_count += 1;
+            CANDIDATE_VOTERS.save(
+                deps.storage,
+                (id, vote.candidate_id, &vote.voter),
+                &Empty {},
+            )?;
+            votes().save(deps.storage, (id, &vote.voter), &vote)?;
+        }
+
+        PROPSALS.save(deps.storage, id, &propsal)?;
+        next_id = next_id.max(id + 1);
+        count += 1;
+    }
+
+    let mut recovered = vec![];
+    for recover in recover_propsals {
+        let propsal = Propsal {
+            id: next_id,
+            title: recover.title,
+            status: recover.status,
+            nft_address: deps.api.addr_validate(&recover.nft_address)?,
+            expiration: Expiration::AtTime(Timestamp::from_seconds(recover.expiration)),
+            voting_starts_at: None,
+            start_height: 0,
+            power_source: PowerSource::TokenIds,
+            voting_strategy: VotingStrategy::Plurality,
+            seats: 1,
+            candidates: recover.candidates,
+            allow_revoting: false,
+            tie_breaker: None,
+            quorum: None,
+            threshold: None,
+            total_power: 0,
+            voter_count: 0,
+            vote_count: 0,
+            executable_at: None,
+            veto_reason: None,
+            proposer: legacy_config.owner.clone(),
+            deposit: None,
+        };
+        PROPSALS.save(deps.storage, next_id, &propsal)?;
+        recovered.push(next_id);
+        next_id += 1;
+        count += 1;
+    }
+
+    PROPSAL_INDEX.save(deps.storage, &next_id)?;
+    PROPSAL_COUNT.save(deps.storage, &count)?;
+
+    Ok(recovered)
+}
@@ -1,35 +1,105 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal};
+use cw721::Cw721ReceiveMsg;
+use cw_utils::{Duration, Expiration, Scheduled};
 
-use crate::state::{Candidate, Config, Propsal, PropsalResult, PropsalStatus};
+use crate::state::{
+    Candidate, Config, CreditAllocation, NftClaim, Ownership, PowerSource, ProposalDeposit,
+    Propsal, PropsalResult, PropsalStatus, Quorum, Role, TieBreaker, Vote, VoteOption,
+    VotingStrategy,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub owner: String,
-    pub max_duration_seconds: u64,
+    pub max_duration: Duration,
+    /// max_duration 과 다른 단위 마감기한의 최대 등록 기간, 없으면 해당 단위 마감기한 불가
+    pub max_duration_secondary: Option<Duration>,
+    pub quorum: Option<Quorum>,
+    pub threshold: Option<Decimal>,
+    pub timelock_seconds: u64,
+    /// Guardian 역할을 부여할 주소
+    pub guardians: Vec<String>,
+    pub nft_address: Option<String>,
+    /// 스테이킹 해제 후 nft 반환까지 대기 기간, 없으면 즉시 반환
+    pub unbonding_period: Option<Duration>,
+    /// 기본 집계 방식, 없으면 Plurality
+    pub voting_strategy: Option<VotingStrategy>,
+    pub proposal_deposit: Option<ProposalDeposit>,
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    /// 아이디 중복 버그로 덮어써진 제안 복구 (이벤트 로그 기준)
+    #[serde(default)]
+    pub recover_propsals: Vec<RecoverPropsal>,
+}
+
+#[cw_serde]
+pub struct RecoverPropsal {
+    pub title: String,
+    pub status: PropsalStatus,
+    pub nft_address: String,
+    /// 0.1.x 마감기한 (초)
+    pub expiration: u64,
+    pub candidates: Vec<Candidate>,
+}
+
+/// 없음으로 되돌릴 수 있는 설정
+#[cw_serde]
+pub enum ConfigField {
+    MaxDurationSecondary,
+    Quorum,
+    Threshold,
+    UnbondingPeriod,
+    ProposalDeposit,
+}
 
 #[cw_serde]
 pub enum ExecuteMsg {
     UpdateConfig {
-        owner: Option<String>,
-        max_duration_seconds: Option<u64>,
+        max_duration: Option<Duration>,
+        max_duration_secondary: Option<Duration>,
+        quorum: Option<Quorum>,
+        threshold: Option<Decimal>,
+        timelock_seconds: Option<u64>,
+        nft_address: Option<String>,
+        unbonding_period: Option<Duration>,
+        voting_strategy: Option<VotingStrategy>,
+        proposal_deposit: Option<ProposalDeposit>,
+    },
+    /// 선택 설정을 없음으로 되돌림 (UpdateConfig 의 None 은 기존 값 유지)
+    ClearConfig {
+        fields: Vec<ConfigField>,
     },
     AddPropsal {
         title: String,
         status: PropsalStatus,
         nft_address: String,
-        expiration: u64,
+        expiration: Expiration,
+        /// SingleChoice 는 선택지가 고정이므로 후보의 메시지만 Yes 통과 시 실행
         candidates: Vec<Candidate>,
+        allow_revoting: bool,
+        tie_breaker: Option<TieBreaker>,
+        /// 없으면 등록 즉시 투표 가능
+        voting_starts_at: Option<Scheduled>,
+        /// 없으면 Config 기본값, 역할 없는 nft 보유자는 지정 불가
+        quorum: Option<Quorum>,
+        /// 없으면 Config 기본값, 역할 없는 nft 보유자는 지정 불가
+        threshold: Option<Decimal>,
+        /// 없으면 거버넌스 nft 제안은 Staked, 그 외에는 TokenIds, 역할 없는 nft 보유자는 지정 불가
+        power_source: Option<PowerSource>,
+        /// 없으면 Config 기본값, 역할 없는 nft 보유자는 지정 불가
+        voting_strategy: Option<VotingStrategy>,
+        /// 당선자 수, 없으면 1
+        seats: Option<u32>,
     },
     UpdatePropsal {
         id: u64,
         title: Option<String>,
         status: Option<PropsalStatus>,
         nft_address: Option<String>,
-        expiration: Option<u64>,
+        expiration: Option<Expiration>,
         candidates: Option<Vec<Candidate>>,
     },
     RemovePropsal {
@@ -38,7 +108,79 @@ pub enum ExecuteMsg {
     ExecuteVote {
         propsal_id: u64,
         candidate_id: u64,
+        token_ids: Vec<String>,
+    },
+    /// 제곱근 투표, power 이하의 credit 을 후보에 배분
+    ExecuteQuadraticVote {
+        propsal_id: u64,
+        allocations: Vec<CreditAllocation>,
+        token_ids: Vec<String>,
+    },
+    /// 순위 투표, 선호 순서대로 후보 인덱스 제출
+    ExecuteRankedVote {
+        propsal_id: u64,
+        ranking: Vec<u64>,
+        token_ids: Vec<String>,
+    },
+    /// 승인 투표, 승인한 후보마다 power 만큼 득표
+    ExecuteApprovalVote {
+        propsal_id: u64,
+        candidate_ids: Vec<u64>,
+        token_ids: Vec<String>,
+    },
+    /// 찬반 투표, 선택지 하나에 power 만큼 득표
+    ExecuteSingleChoiceVote {
+        propsal_id: u64,
+        option: VoteOption,
+        token_ids: Vec<String>,
+    },
+    Finalize {
+        id: u64,
+    },
+    ExecuteProposal {
+        id: u64,
     },
+    /// 통과한 제안의 메시지 일괄 실행, 컨트랙트 자신만 호출 가능
+    ExecutePropsalMsgs {
+        id: u64,
+    },
+    CancelProposal {
+        id: u64,
+    },
+    Veto {
+        id: u64,
+        reason: String,
+    },
+    GrantRole {
+        address: String,
+        role: Role,
+    },
+    RevokeRole {
+        address: String,
+        role: Role,
+    },
+    /// 새 오너 지정, 새 오너가 수락해야 이전됨
+    ProposeNewOwner {
+        new_owner: String,
+        expiry: Option<Expiration>,
+    },
+    AcceptOwnership {},
+    CancelOwnershipTransfer {},
+    /// 소유권 포기, 이후 Admin 은 부여된 역할과 컨트랙트 자신만 가짐
+    RenounceOwnership {},
+    /// 거버넌스 nft 스테이킹 (cw721 SendNft)
+    ReceiveNft(Cw721ReceiveMsg),
+    /// 스테이킹 해제, unbonding 기간이 있으면 ClaimNfts 로 반환
+    Unstake {
+        token_ids: Vec<String>,
+    },
+    /// unbonding 기간이 지난 nft 반환
+    ClaimNfts {},
+    /// 스테이킹 power 위임, 위임자가 직접 투표하면 해당 제안에서는 위임 무시
+    Delegate {
+        to: String,
+    },
+    Undelegate {},
 }
 
 #[cw_serde]
@@ -59,6 +201,59 @@ pub enum QueryMsg {
     BlockHeight { num: u64 },
     #[returns(BlockIndexResponse)]
     BlockIndex {},
+    #[returns(ProposalCountResponse)]
+    ProposalCount {},
+    #[returns(VoteResponse)]
+    Vote { propsal_id: u64, voter: String },
+    #[returns(VotesResponse)]
+    Votes {
+        propsal_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(VotesResponse)]
+    VoterVotes {
+        voter: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(VotesResponse)]
+    CandidateVotes {
+        propsal_id: u64,
+        candidate_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Ownership)]
+    Ownership {},
+    #[returns(PendingOwnerResponse)]
+    PendingOwner {},
+    #[returns(RolesResponse)]
+    Roles { address: String },
+    #[returns(RoleMembersResponse)]
+    ListRoleMembers {
+        role: Role,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(StakedTokensResponse)]
+    StakedTokens {
+        address: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(TotalStakedResponse)]
+    TotalStaked {},
+    #[returns(PendingClaimsResponse)]
+    PendingClaims { address: String },
+    #[returns(DelegatedPowerResponse)]
+    DelegatedPower { address: String },
+    #[returns(DelegationsResponse)]
+    Delegations {
+        delegate: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -90,3 +285,68 @@ pub struct BlockHeightResponse {
 pub struct BlockIndexResponse {
     pub block_index: u64,
 }
+
+#[cw_serde]
+pub struct ProposalCountResponse {
+    /// 다음 제안 아이디
+    pub next_id: u64,
+    /// 생성된 제안 수
+    pub total: u64,
+}
+
+#[cw_serde]
+pub struct VoteResponse {
+    pub vote: Option<Vote>,
+}
+
+#[cw_serde]
+pub struct VotesResponse {
+    pub votes: Vec<Vote>,
+}
+
+#[cw_serde]
+pub struct RolesResponse {
+    pub roles: Vec<Role>,
+}
+
+#[cw_serde]
+pub struct RoleMembersResponse {
+    pub members: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct PendingOwnerResponse {
+    pub pending_owner: Option<Addr>,
+    pub pending_expiry: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct StakedTokensResponse {
+    pub token_ids: Vec<String>,
+    /// 현재 스테이킹 power
+    pub power: u64,
+}
+
+#[cw_serde]
+pub struct TotalStakedResponse {
+    pub total: u64,
+}
+
+#[cw_serde]
+pub struct PendingClaimsResponse {
+    pub claims: Vec<NftClaim>,
+}
+
+#[cw_serde]
+pub struct DelegatedPowerResponse {
+    /// 위임받은 power
+    pub power: u64,
+    /// 이 주소가 위임한 대리인
+    pub delegate: Option<Addr>,
+}
+
+#[cw_serde]
+pub struct DelegationsResponse {
+    /// 대리인에게 위임한 주소
+    pub delegators: Vec<Addr>,
+}
@@ -0,0 +1,95 @@
+use cosmwasm_std::{Addr, StdResult, Storage};
+
+use crate::state::{
+    Propsal, DELEGATED_POWER, DELEGATES, OVERRIDDEN_POWER, STAKED_POWER, TOTAL_STAKED,
+};
+
+/// 스테이킹 power 증가, 위임 중이면 대리인 power 도 증가
+pub fn add_staked_power(
+    storage: &mut dyn Storage,
+    height: u64,
+    staker: &Addr,
+    amount: u64,
+) -> StdResult<()> {
+    STAKED_POWER.update(storage, staker, height, |power| -> StdResult<_> {
+        Ok(power.unwrap_or_default() + amount)
+    })?;
+    TOTAL_STAKED.update(storage, height, |total| -> StdResult<_> {
+        Ok(total.unwrap_or_default() + amount)
+    })?;
+    if let Some(delegate) = DELEGATES.may_load(storage, staker)? {
+        add_delegated_power(storage, height, &delegate, amount)?;
+    }
+
+    Ok(())
+}
+
+/// 스테이킹 power 차감, 위임 중이면 대리인 power 도 차감
+pub fn sub_staked_power(
+    storage: &mut dyn Storage,
+    height: u64,
+    staker: &Addr,
+    amount: u64,
+) -> StdResult<()> {
+    STAKED_POWER.update(storage, staker, height, |power| -> StdResult<_> {
+        Ok(power.unwrap_or_default() - amount)
+    })?;
+    TOTAL_STAKED.update(storage, height, |total| -> StdResult<_> {
+        Ok(total.unwrap_or_default() - amount)
+    })?;
+    if let Some(delegate) = DELEGATES.may_load(storage, staker)? {
+        sub_delegated_power(storage, height, &delegate, amount)?;
+    }
+
+    Ok(())
+}
+
+/// 위임받은 power 증가
+pub fn add_delegated_power(
+    storage: &mut dyn Storage,
+    height: u64,
+    delegate: &Addr,
+    amount: u64,
+) -> StdResult<()> {
+    DELEGATED_POWER.update(storage, delegate, height, |power| -> StdResult<_> {
+        Ok(power.unwrap_or_default() + amount)
+    })?;
+
+    Ok(())
+}
+
+/// 위임받은 power 차감
+pub fn sub_delegated_power(
+    storage: &mut dyn Storage,
+    height: u64,
+    delegate: &Addr,
+    amount: u64,
+) -> StdResult<()> {
+    DELEGATED_POWER.update(storage, delegate, height, |power| -> StdResult<_> {
+        Ok(power.unwrap_or_default() - amount)
+    })?;
+
+    Ok(())
+}
+
+/// 제안 등록 시점 기준 투표 power
+///
+/// 자신의 스테이킹 power 에 위임받은 power 를 더하고, 그 중 위임자가 직접 투표해
+/// 가져간 power 는 뺀다. 위임 중이어도 직접 투표하면 자신의 power 로 투표한다.
+pub fn staked_voting_power(
+    storage: &dyn Storage,
+    propsal: &Propsal,
+    voter: &Addr,
+) -> StdResult<u64> {
+    let own = STAKED_POWER
+        .may_load_at_height(storage, voter, propsal.start_height)?
+        .unwrap_or_default();
+    let delegated = DELEGATED_POWER
+        .may_load_at_height(storage, voter, propsal.start_height)?
+        .unwrap_or_default();
+    let overridden = OVERRIDDEN_POWER
+        .may_load(storage, (propsal.id, voter))?
+        .unwrap_or_default();
+
+    Ok(own + delegated - overridden)
+}
@@ -1,26 +1,82 @@
-use std::collections::HashMap;
+use cosmwasm_std::{entry_point, to_json_binary, Binary, Deps, Env, Order::Ascending, StdResult};
 
-use cosmwasm_std::{entry_point, to_binary, Binary, Deps, Env, Order::Ascending, StdResult};
-
-use cw_storage_plus::Bounder;
+use cw_storage_plus::{Bound, Bounder};
 
 use crate::msg::{
-    BlockHeightResponse, BlockIndexResponse, ConfigResponse, PropsalResponse,
-    PropsalResultResponse, PropsalsResponse, QueryMsg,
+    BlockHeightResponse, BlockIndexResponse, ConfigResponse, DelegatedPowerResponse,
+    DelegationsResponse, PendingClaimsResponse, PendingOwnerResponse, ProposalCountResponse,
+    PropsalResponse, PropsalResultResponse, PropsalsResponse, QueryMsg, RoleMembersResponse,
+    RolesResponse, StakedTokensResponse, TotalStakedResponse, VoteResponse, VotesResponse,
+};
+use crate::state::{
+    votes, Propsal, Role, BLOCK_HEIGHTS, BLOCK_INDEX, CANDIDATE_VOTERS, CONFIG, DELEGATED_POWER,
+    DELEGATES, DELEGATIONS, NFT_CLAIMS, OWNERSHIP, PROPSALS, PROPSAL_COUNT, PROPSAL_INDEX,
+    PROPSAL_RESULTS, ROLES, STAKED_NFTS, STAKED_POWER, TOTAL_STAKED,
 };
-use crate::state::{Candidate, PropsalResult, BLOCK_HEIGHTS, BLOCK_INDEX, CONFIG, PROPSALS};
+use crate::tally::compute_result;
 
 #[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::Config {} => to_binary(&query_config(deps)?),
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
         QueryMsg::Propsals { start_after, limit } => {
-            to_binary(&query_propsals(deps, start_after, limit)?)
+            to_json_binary(&query_propsals(deps, env, start_after, limit)?)
+        }
+        QueryMsg::Propsal { id } => to_json_binary(&query_propsal(deps, env, id)?),
+        QueryMsg::PropsalResult { id } => to_json_binary(&query_propsal_result(deps, env, id)?),
+        QueryMsg::BlockHeight { num } => to_json_binary(&query_block_height(deps, num)?),
+        QueryMsg::BlockIndex {} => to_json_binary(&query_block_index(deps)?),
+        QueryMsg::ProposalCount {} => to_json_binary(&query_proposal_count(deps)?),
+        QueryMsg::Vote { propsal_id, voter } => {
+            to_json_binary(&query_vote(deps, propsal_id, voter)?)
         }
-        QueryMsg::Propsal { id } => to_binary(&query_propsal(deps, id)?),
-        QueryMsg::PropsalResult { id } => to_binary(&query_propsal_result(deps, id)?),
-        QueryMsg::BlockHeight { num } => to_binary(&query_block_height(deps, num.clone())?),
-        QueryMsg::BlockIndex {} => to_binary(&query_block_index(deps)?),
+        QueryMsg::Votes {
+            propsal_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_votes(deps, propsal_id, start_after, limit)?),
+        QueryMsg::VoterVotes {
+            voter,
+            start_after,
+            limit,
+        } => to_json_binary(&query_voter_votes(deps, voter, start_after, limit)?),
+        QueryMsg::CandidateVotes {
+            propsal_id,
+            candidate_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_candidate_votes(
+            deps,
+            propsal_id,
+            candidate_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::Ownership {} => to_json_binary(&OWNERSHIP.load(deps.storage)?),
+        QueryMsg::PendingOwner {} => to_json_binary(&query_pending_owner(deps)?),
+        QueryMsg::Roles { address } => to_json_binary(&query_roles(deps, address)?),
+        QueryMsg::ListRoleMembers {
+            role,
+            start_after,
+            limit,
+        } => to_json_binary(&query_role_members(deps, role, start_after, limit)?),
+        QueryMsg::StakedTokens {
+            address,
+            start_after,
+            limit,
+        } => to_json_binary(&query_staked_tokens(deps, address, start_after, limit)?),
+        QueryMsg::TotalStaked {} => to_json_binary(&query_total_staked(deps)?),
+        QueryMsg::PendingClaims { address } => {
+            to_json_binary(&query_pending_claims(deps, address)?)
+        }
+        QueryMsg::DelegatedPower { address } => {
+            to_json_binary(&query_delegated_power(deps, address)?)
+        }
+        QueryMsg::Delegations {
+            delegate,
+            start_after,
+            limit,
+        } => to_json_binary(&query_delegations(deps, delegate, start_after, limit)?),
     }
 }
 
@@ -35,58 +91,46 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
 
 fn query_propsals(
     deps: Deps,
+    env: Env,
     start_after: Option<u64>,
     limit: Option<u32>,
 ) -> StdResult<PropsalsResponse> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
-    let start = if let Some(start_after) = start_after {
-        Some(start_after.exclusive_bound().unwrap())
-    } else {
-        None
-    };
+    let start = start_after.map(|start_after| start_after.exclusive_bound().unwrap());
 
     let result: StdResult<Vec<_>> = PROPSALS
         .range(deps.storage, start, None, Ascending)
         .take(limit)
         .collect();
 
-    let propsals = result?.into_iter().map(|(_, v)| v).collect();
+    let propsals = result?
+        .into_iter()
+        .map(|(_, propsal)| with_current_status(propsal, &env))
+        .collect();
 
     Ok(PropsalsResponse { propsals })
 }
 
-fn query_propsal(deps: Deps, id: u64) -> StdResult<PropsalResponse> {
-    let propsal = PROPSALS.load(deps.storage, id)?;
+fn query_propsal(deps: Deps, env: Env, id: u64) -> StdResult<PropsalResponse> {
+    let propsal = with_current_status(PROPSALS.load(deps.storage, id)?, &env);
 
     Ok(PropsalResponse { propsal })
 }
 
-fn query_propsal_result(deps: Deps, id: u64) -> StdResult<PropsalResultResponse> {
-    let propsal = PROPSALS.load(deps.storage, id)?;
-
-    let mut candidate_counts: HashMap<u64, u32> = HashMap::new();
-
-    // 투표수 세기
-    for vote in propsal.clone().votes {
-        let count = candidate_counts.entry(vote.candidate_id).or_insert(0);
-        *count += 1;
-    }
-
-    // TODO: power 계산 필요, 가장 많은 투표를 받은 후보 찾기
-    let (max_candidate_id, _) = candidate_counts
-        .iter()
-        .max_by_key(|(_, &count)| count)
-        .map(|(&candidate_id, &count)| (candidate_id, count))
-        .unwrap();
-
-    let winner = Candidate {
-        id: max_candidate_id,
-        name: String::from(""),
-    };
+/// 저장된 상태 대신 블록 기준 현재 상태로 변경
+fn with_current_status(mut propsal: Propsal, env: &Env) -> Propsal {
+    propsal.status = propsal.current_status(&env.block);
+    propsal
+}
 
-    let propsal_result = PropsalResult {
-        propsal: propsal.clone(),
-        winner,
+fn query_propsal_result(deps: Deps, env: Env, id: u64) -> StdResult<PropsalResultResponse> {
+    // 확정된 결과가 없으면 현재 상태의 제안과 현재 집계로 계산
+    let propsal_result = match PROPSAL_RESULTS.may_load(deps.storage, id)? {
+        Some(propsal_result) => propsal_result,
+        None => {
+            let propsal = with_current_status(PROPSALS.load(deps.storage, id)?, &env);
+            compute_result(deps, &propsal)?
+        }
     };
 
     Ok(PropsalResultResponse { propsal_result })
@@ -103,3 +147,206 @@ fn query_block_index(deps: Deps) -> StdResult<BlockIndexResponse> {
 
     Ok(BlockIndexResponse { block_index })
 }
+
+fn query_proposal_count(deps: Deps) -> StdResult<ProposalCountResponse> {
+    let next_id = PROPSAL_INDEX.load(deps.storage)?;
+    let total = PROPSAL_COUNT.load(deps.storage)?;
+
+    Ok(ProposalCountResponse { next_id, total })
+}
+
+fn query_vote(deps: Deps, propsal_id: u64, voter: String) -> StdResult<VoteResponse> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let vote = votes().may_load(deps.storage, (propsal_id, &voter))?;
+
+    Ok(VoteResponse { vote })
+}
+
+fn query_votes(
+    deps: Deps,
+    propsal_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<VotesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|voter| deps.api.addr_validate(&voter))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    let votes = votes()
+        .prefix(propsal_id)
+        .range(deps.storage, start, None, Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, vote)| vote))
+        .collect::<StdResult<_>>()?;
+
+    Ok(VotesResponse { votes })
+}
+
+fn query_voter_votes(
+    deps: Deps,
+    voter: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<VotesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let voter = deps.api.addr_validate(&voter)?;
+    let start = start_after.map(|propsal_id| Bound::exclusive((propsal_id, voter.clone())));
+
+    let votes = votes()
+        .idx
+        .voter
+        .prefix(voter)
+        .range(deps.storage, start, None, Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, vote)| vote))
+        .collect::<StdResult<_>>()?;
+
+    Ok(VotesResponse { votes })
+}
+
+fn query_candidate_votes(
+    deps: Deps,
+    propsal_id: u64,
+    candidate_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<VotesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|voter| deps.api.addr_validate(&voter))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    let votes = CANDIDATE_VOTERS
+        .prefix((propsal_id, candidate_id))
+        .keys(deps.storage, start, None, Ascending)
+        .take(limit)
+        .map(|voter| votes().load(deps.storage, (propsal_id, &voter?)))
+        .collect::<StdResult<_>>()?;
+
+    Ok(VotesResponse { votes })
+}
+
+fn query_pending_owner(deps: Deps) -> StdResult<PendingOwnerResponse> {
+    let ownership = OWNERSHIP.load(deps.storage)?;
+
+    Ok(PendingOwnerResponse {
+        pending_owner: ownership.pending_owner,
+        pending_expiry: ownership.pending_expiry,
+    })
+}
+
+/// 부여된 역할 (오너의 암묵적 Admin 제외)
+fn query_roles(deps: Deps, address: String) -> StdResult<RolesResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let roles = ROLES.may_load(deps.storage, &address)?.unwrap_or_default();
+
+    Ok(RolesResponse { roles })
+}
+
+/// 역할을 부여받은 주소 목록 (오너의 암묵적 Admin 제외)
+fn query_role_members(
+    deps: Deps,
+    role: Role,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<RoleMembersResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+
+    let members = ROLES
+        .range(
+            deps.storage,
+            start.as_ref().map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .filter(|item| {
+            item.as_ref()
+                .map(|(_, roles)| roles.contains(&role))
+                .unwrap_or(true)
+        })
+        .take(limit)
+        .map(|item| item.map(|(address, _)| address))
+        .collect::<StdResult<_>>()?;
+
+    Ok(RoleMembersResponse { members })
+}
+
+fn query_staked_tokens(
+    deps: Deps,
+    address: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<StakedTokensResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let address = deps.api.addr_validate(&address)?;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let token_ids = STAKED_NFTS
+        .prefix(&address)
+        .keys(deps.storage, start, None, Ascending)
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+    let power = STAKED_POWER
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+
+    Ok(StakedTokensResponse { token_ids, power })
+}
+
+fn query_total_staked(deps: Deps) -> StdResult<TotalStakedResponse> {
+    let total = TOTAL_STAKED.may_load(deps.storage)?.unwrap_or_default();
+
+    Ok(TotalStakedResponse { total })
+}
+
+/// 반환 대기 중인 nft (반환 가능 여부는 release_at 기준)
+fn query_pending_claims(deps: Deps, address: String) -> StdResult<PendingClaimsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let claims = NFT_CLAIMS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+
+    Ok(PendingClaimsResponse { claims })
+}
+
+fn query_delegated_power(deps: Deps, address: String) -> StdResult<DelegatedPowerResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let power = DELEGATED_POWER
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    let delegate = DELEGATES.may_load(deps.storage, &address)?;
+
+    Ok(DelegatedPowerResponse { power, delegate })
+}
+
+fn query_delegations(
+    deps: Deps,
+    delegate: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<DelegationsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let delegate = deps.api.addr_validate(&delegate)?;
+    let start = start_after
+        .map(|delegator| deps.api.addr_validate(&delegator))
+        .transpose()?;
+
+    let delegators = DELEGATIONS
+        .prefix(&delegate)
+        .keys(
+            deps.storage,
+            start.as_ref().map(Bound::exclusive),
+            None,
+            Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+
+    Ok(DelegationsResponse { delegators })
+}
@@ -0,0 +1,256 @@
+use cosmwasm_std::{Decimal, Order::Ascending, StdResult, Storage};
+
+use crate::state::{votes, Propsal, RoundCount, RunoffRound, VoteTransfer};
+
+/// 결선 집계용 투표
+struct Ballot {
+    /// 남은 가치 (STV 잉여표 이양 시 줄어듦)
+    value: Decimal,
+    /// 후보 순위
+    ranking: Vec<u64>,
+}
+
+fn load_ballots(storage: &dyn Storage, propsal: &Propsal) -> StdResult<Vec<Ballot>> {
+    votes()
+        .prefix(propsal.id)
+        .range(storage, None, None, Ascending)
+        .map(|item| {
+            item.map(|(_, vote)| Ballot {
+                value: Decimal::from_ratio(vote.power, 1u64),
+                ranking: vote.ranking,
+            })
+        })
+        .collect()
+}
+
+/// 남은 후보 중 가장 높은 순위의 후보
+fn preference(ballot: &Ballot, continuing: &[u64]) -> Option<u64> {
+    ballot
+        .ranking
+        .iter()
+        .copied()
+        .find(|id| continuing.contains(id))
+}
+
+/// 남은 후보별 득표와 소진된 power
+fn count(ballots: &[Ballot], continuing: &[u64]) -> (Vec<RoundCount>, Decimal) {
+    let mut counts: Vec<RoundCount> = continuing
+        .iter()
+        .map(|candidate_id| RoundCount {
+            candidate_id: *candidate_id,
+            power: Decimal::zero(),
+        })
+        .collect();
+    let mut exhausted = Decimal::zero();
+    for ballot in ballots {
+        match preference(ballot, continuing)
+            .and_then(|id| counts.iter().position(|c| c.candidate_id == id))
+        {
+            Some(idx) => counts[idx].power += ballot.value,
+            None => exhausted += ballot.value,
+        }
+    }
+
+    (counts, exhausted)
+}
+
+/// from 후보에게 세어진 투표가 다음 순위 후보로 옮겨가는 내역
+fn transfers(ballots: &[Ballot], from: u64, prev: &[u64], next: &[u64]) -> Vec<VoteTransfer> {
+    let mut transfers: Vec<VoteTransfer> = vec![];
+    for ballot in ballots {
+        if preference(ballot, prev) != Some(from) || ballot.value.is_zero() {
+            continue;
+        }
+        let to = preference(ballot, next);
+        match transfers.iter_mut().find(|t| t.to == to) {
+            Some(transfer) => transfer.power += ballot.value,
+            None => transfers.push(VoteTransfer {
+                from,
+                to,
+                power: ballot.value,
+            }),
+        }
+    }
+    transfers.sort_by_key(|t| (t.to.is_none(), t.to));
+
+    transfers
+}
+
+/// 최저 득표 후보, 동점이면 후보 인덱스가 가장 큰 후보
+fn lowest(counts: &[RoundCount]) -> Option<u64> {
+    counts
+        .iter()
+        .min_by(|a, b| {
+            a.power
+                .cmp(&b.power)
+                .then(b.candidate_id.cmp(&a.candidate_id))
+        })
+        .map(|c| c.candidate_id)
+}
+
+/// 순위 투표 결선 (instant-runoff)
+///
+/// 매 라운드 각 투표를 남은 후보 중 가장 높은 순위의 후보에게 세고, 과반 득표자가
+/// 나오거나 남은 후보가 모두 동점이 될 때까지 최저 득표 후보를 한 명씩 탈락시킨다.
+/// 최저 득표가 동점이면 후보 인덱스가 가장 큰 후보가 탈락한다.
+pub fn instant_runoff(storage: &dyn Storage, propsal: &Propsal) -> StdResult<Vec<RunoffRound>> {
+    let ballots = load_ballots(storage, propsal)?;
+
+    let mut continuing: Vec<u64> = propsal.candidates.iter().map(|c| c.id).collect();
+    let mut rounds = vec![];
+    while !continuing.is_empty() {
+        let (counts, exhausted) = count(&ballots, &continuing);
+        let active: Decimal = counts.iter().map(|c| c.power).sum();
+        let top = counts.iter().max_by(|a, b| {
+            a.power
+                .cmp(&b.power)
+                .then(b.candidate_id.cmp(&a.candidate_id))
+        });
+        let all_tied = counts.iter().all(|c| Some(c.power) == top.map(|t| t.power));
+        let majority = top.filter(|t| !active.is_zero() && t.power + t.power > active);
+
+        if active.is_zero() || majority.is_some() || all_tied {
+            rounds.push(RunoffRound {
+                round: rounds.len() as u32 + 1,
+                elected: majority.map(|t| t.candidate_id).into_iter().collect(),
+                counts,
+                eliminated: None,
+                transfers: vec![],
+                exhausted,
+            });
+            break;
+        }
+
+        let eliminated = match lowest(&counts) {
+            Some(eliminated) => eliminated,
+            None => break,
+        };
+        let next: Vec<u64> = continuing
+            .iter()
+            .copied()
+            .filter(|id| *id != eliminated)
+            .collect();
+        rounds.push(RunoffRound {
+            round: rounds.len() as u32 + 1,
+            counts,
+            elected: vec![],
+            eliminated: Some(eliminated),
+            transfers: transfers(&ballots, eliminated, &continuing, &next),
+            exhausted,
+        });
+        continuing = next;
+    }
+
+    Ok(rounds)
+}
+
+/// 단기 이양식 투표 (STV), 결선 라운드와 Droop quota 반환
+///
+/// quota = floor(총 power / (seats + 1)) + 1. 매 라운드 quota 이상 득표한 후보를
+/// 득표 순 (동점이면 후보 인덱스 순) 으로 당선시키고, 당선자에게 세어진 투표는
+/// 잉여표 / 득표 비율로 가치를 줄여 다음 순위로 이양한다 (Gregory 방식).
+/// 당선자가 없으면 최저 득표 후보 (동점이면 인덱스가 큰 후보) 를 탈락시키고,
+/// 남은 후보가 남은 자리 이하가 되면 모두 득표 순으로 당선시킨다.
+pub fn single_transferable_vote(
+    storage: &dyn Storage,
+    propsal: &Propsal,
+) -> StdResult<(Vec<RunoffRound>, Option<Decimal>)> {
+    let mut ballots = load_ballots(storage, propsal)?;
+    let total: Decimal = ballots.iter().map(|ballot| ballot.value).sum();
+    if total.is_zero() {
+        return Ok((vec![], None));
+    }
+    let seats = propsal.seats as usize;
+    let quota = (total / Decimal::from_ratio(seats as u64 + 1, 1u64)).floor() + Decimal::one();
+
+    let mut continuing: Vec<u64> = propsal.candidates.iter().map(|c| c.id).collect();
+    let mut elected_count = 0;
+    let mut rounds = vec![];
+    while elected_count < seats && !continuing.is_empty() {
+        let (counts, exhausted) = count(&ballots, &continuing);
+        let mut ranked: Vec<&RoundCount> = counts.iter().collect();
+        ranked.sort_by(|a, b| {
+            b.power
+                .cmp(&a.power)
+                .then(a.candidate_id.cmp(&b.candidate_id))
+        });
+
+        // 남은 후보가 남은 자리 이하이면 모두 당선
+        if continuing.len() <= seats - elected_count {
+            let elected = ranked.iter().map(|c| c.candidate_id).collect();
+            rounds.push(RunoffRound {
+                round: rounds.len() as u32 + 1,
+                counts,
+                elected,
+                eliminated: None,
+                transfers: vec![],
+                exhausted,
+            });
+            break;
+        }
+
+        let elected: Vec<(u64, Decimal)> = ranked
+            .iter()
+            .filter(|c| c.power >= quota)
+            .take(seats - elected_count)
+            .map(|c| (c.candidate_id, c.power))
+            .collect();
+        let round = rounds.len() as u32 + 1;
+        if elected.is_empty() {
+            let eliminated = match lowest(&counts) {
+                Some(eliminated) => eliminated,
+                None => break,
+            };
+            let next: Vec<u64> = continuing
+                .iter()
+                .copied()
+                .filter(|id| *id != eliminated)
+                .collect();
+            rounds.push(RunoffRound {
+                round,
+                counts,
+                elected: vec![],
+                eliminated: Some(eliminated),
+                transfers: transfers(&ballots, eliminated, &continuing, &next),
+                exhausted,
+            });
+            continuing = next;
+            continue;
+        }
+
+        // 당선자의 투표는 잉여표 비율만큼의 가치로 이양
+        let next: Vec<u64> = continuing
+            .iter()
+            .copied()
+            .filter(|id| !elected.iter().any(|(elected_id, _)| elected_id == id))
+            .collect();
+        for (candidate_id, power) in elected.iter() {
+            let ratio = (*power - quota) / *power;
+            for ballot in ballots.iter_mut() {
+                if preference(ballot, &continuing) == Some(*candidate_id) {
+                    ballot.value *= ratio;
+                }
+            }
+        }
+        let transfers = elected
+            .iter()
+            .flat_map(|(candidate_id, _)| transfers(&ballots, *candidate_id, &continuing, &next))
+            .collect();
+
+        elected_count += elected.len();
+        rounds.push(RunoffRound {
+            round,
+            counts,
+            elected: elected
+                .into_iter()
+                .map(|(candidate_id, _)| candidate_id)
+                .collect(),
+            eliminated: None,
+            transfers,
+            exhausted,
+        });
+        continuing = next;
+    }
+
+    Ok((rounds, Some(quota)))
+}
@@ -1,7 +1,10 @@
 use cosmwasm_schema::cw_serde;
 
-use cosmwasm_std::Addr;
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Decimal, Empty, Uint128};
+use cw_storage_plus::{
+    Index, IndexList, IndexedMap, Item, Map, MultiIndex, SnapshotItem, SnapshotMap, Strategy,
+};
+use cw_utils::{Duration, Expiration, Scheduled};
 
 /// 컨트랙트 설정
 #[cw_serde]
@@ -9,9 +12,100 @@ pub struct Config {
     /// 시작 블록
     pub start_blocknumber: u64,
     /// 최대 등록 기간
-    pub max_duration_seconds: u64,
-    /// 컨트랙트 오너
-    pub owner: Addr,
+    pub max_duration: Duration,
+    /// max_duration 과 다른 단위 (블록 높이, 시간) 마감기한의 최대 등록 기간
+    #[serde(default)]
+    pub max_duration_secondary: Option<Duration>,
+    /// 기본 정족수
+    pub quorum: Option<Quorum>,
+    /// 기본 통과 기준 (최다 득표자의 득표 비율이 초과해야 하는 값)
+    pub threshold: Option<Decimal>,
+    /// 기본 집계 방식
+    #[serde(default)]
+    pub voting_strategy: VotingStrategy,
+    /// 통과 후 실행까지 대기 시간
+    pub timelock_seconds: u64,
+    /// 거버넌스 nft 주소, 보유자는 보증금을 내고 제안 가능
+    pub nft_address: Option<Addr>,
+    /// 스테이킹 해제 후 nft 반환까지 대기 기간, 없으면 즉시 반환
+    pub unbonding_period: Option<Duration>,
+    /// 제안 보증금
+    pub proposal_deposit: Option<ProposalDeposit>,
+}
+
+/// 컨트랙트 소유권 (cw-ownable 의 Ownership 과 같은 형태)
+#[cw_serde]
+pub struct Ownership {
+    /// 오너, 포기한 경우 없음
+    pub owner: Option<Addr>,
+    /// 소유권 이전 대기 중인 주소
+    pub pending_owner: Option<Addr>,
+    /// 소유권 이전 수락 기한
+    pub pending_expiry: Option<Expiration>,
+}
+
+/// 제안 보증금 설정
+#[cw_serde]
+pub struct ProposalDeposit {
+    /// 보증금 토큰
+    pub denom: DepositDenom,
+    /// 보증금 수량
+    pub amount: Uint128,
+    /// 반환 정책
+    pub refund_policy: DepositRefundPolicy,
+}
+
+/// 보증금 토큰
+#[cw_serde]
+pub enum DepositDenom {
+    Native { denom: String },
+    Cw20 { address: Addr },
+}
+
+/// 보증금 반환 정책, 반환하지 않는 보증금은 DAO 에 귀속
+#[cw_serde]
+pub enum DepositRefundPolicy {
+    /// 거부권 행사된 경우를 제외하고 항상 반환
+    Always,
+    /// 정족수를 채운 경우 반환
+    QuorumMet,
+    /// 통과한 경우에만 반환
+    OnlyPassed,
+}
+
+/// 제안에 걸린 보증금
+#[cw_serde]
+pub struct Deposit {
+    /// 보증금 토큰
+    pub denom: DepositDenom,
+    /// 보증금 수량
+    pub amount: Uint128,
+    /// 반환 정책 (제안 등록 시점 기준)
+    pub refund_policy: DepositRefundPolicy,
+    /// 상태
+    pub status: DepositStatus,
+}
+
+/// 보증금 상태
+#[cw_serde]
+pub enum DepositStatus {
+    /// 보관 중
+    Held,
+    /// 제안자에게 반환
+    Refunded,
+    /// DAO 에 귀속
+    Forfeited,
+    /// 소각
+    Burned,
+}
+
+/// 정족수
+#[cw_serde]
+pub enum Quorum {
+    /// 최소 투표 power
+    AbsolutePower { power: u64 },
+    /// nft 총 발행량 대비 최소 투표 power 비율
+    Percentage { percentage: Decimal },
 }
 
 /// 투표 제안서
@@ -26,22 +120,191 @@ pub struct Propsal {
     /// 투표 가능한 nft 주소
     pub nft_address: Addr,
     /// 투표 마감기한
-    pub expiration: u64,
-    /// 투표
-    pub votes: Vec<Vote>,
+    pub expiration: Expiration,
+    /// 투표 시작 시점, 없으면 등록 즉시
+    pub voting_starts_at: Option<Scheduled>,
+    /// 등록 블록 높이 (스테이킹 power 스냅샷 기준)
+    pub start_height: u64,
+    /// 투표 power 계산 방식
+    pub power_source: PowerSource,
+    /// 득표 집계 방식
+    pub voting_strategy: VotingStrategy,
+    /// 당선 인원
+    pub seats: u32,
     /// 후보
     pub candidates: Vec<Candidate>,
+    /// 투표 마감 전 재투표 허용 여부
+    pub allow_revoting: bool,
+    /// 동점 처리 방식, 없으면 동점으로 결과 확정
+    pub tie_breaker: Option<TieBreaker>,
+    /// 정족수
+    pub quorum: Option<Quorum>,
+    /// 통과 기준
+    pub threshold: Option<Decimal>,
+    /// 총 투표 power
+    pub total_power: u64,
+    /// 투표자 수
+    pub voter_count: u64,
+    /// 재투표 포함 투표 횟수 (득표 순서 기록용)
+    pub vote_count: u64,
+    /// 실행 가능 시간 (Queued 인 경우)
+    pub executable_at: Option<u64>,
+    /// 거부권 행사 사유
+    pub veto_reason: Option<String>,
+    /// 제안자
+    pub proposer: Addr,
+    /// 보증금
+    pub deposit: Option<Deposit>,
 }
 
 /// 제안서 상태
 #[cw_serde]
 pub enum PropsalStatus {
+    /// 투표 시작 전
+    Pending,
     /// 진행
     Enabled,
     /// 미진행
     Disabled,
     /// 종료
     Finalized,
+    /// 통과, 실행 대기
+    Queued,
+    /// 실행 대기 중 취소
+    Cancelled,
+    /// 가디언 거부권 행사
+    Vetoed,
+    /// 실행 완료
+    Executed,
+    /// 실행 실패
+    ExecutionFailed,
+    /// 투표 마감, 결과 확정 전 (저장되지 않고 조회 시에만 사용)
+    Expired,
+}
+
+impl PropsalStatus {
+    /// 결과 확정 전 상태
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self,
+            PropsalStatus::Pending
+                | PropsalStatus::Enabled
+                | PropsalStatus::Disabled
+                | PropsalStatus::Expired
+        )
+    }
+}
+
+impl Propsal {
+    /// 블록 기준 현재 상태 (투표 시작, 마감 반영)
+    pub fn current_status(&self, block: &BlockInfo) -> PropsalStatus {
+        let status = match (&self.status, &self.voting_starts_at) {
+            (PropsalStatus::Pending, Some(voting_starts_at))
+                if voting_starts_at.is_triggered(block) =>
+            {
+                PropsalStatus::Enabled
+            }
+            (status, _) => status.clone(),
+        };
+
+        match status {
+            PropsalStatus::Pending | PropsalStatus::Enabled
+                if self.expiration.is_expired(block) =>
+            {
+                PropsalStatus::Expired
+            }
+            status => status,
+        }
+    }
+}
+
+/// 권한 역할
+#[cw_serde]
+pub enum Role {
+    /// 역할 관리, 모든 관리 기능 (오너는 항상 Admin)
+    Admin,
+    /// 제안 추가, 수정, 삭제
+    ProposalCreator,
+    /// 제안 취소, 거부권 행사
+    Guardian,
+    /// 컨트랙트 설정 변경
+    ConfigManager,
+}
+
+/// 투표 power 계산 방식
+#[cw_serde]
+pub enum PowerSource {
+    /// 투표 시 제출한 nft 토큰 (1 NFT = 1 power)
+    TokenIds,
+    /// 제안 등록 시점에 스테이킹된 거버넌스 nft
+    Staked,
+}
+
+/// 득표 집계 방식
+#[cw_serde]
+#[derive(Default)]
+pub enum VotingStrategy {
+    /// 한 후보에게 power 만큼 득표
+    #[default]
+    Plurality,
+    /// power 만큼의 credit 을 여러 후보에 나눠 쓰고, 후보별 credit 의 제곱근만큼 득표
+    Quadratic,
+    /// 후보 순위를 제출하고 결선 (instant-runoff) 으로 과반 득표자 결정
+    RankedChoice,
+    /// 여러 후보를 승인하고 승인한 후보마다 power 만큼 득표, 없으면 승인 수 제한 없음
+    Approval { max_choices: Option<u32> },
+    /// 순위 투표로 seats 명 선출, Droop quota 를 넘는 잉여표는 다음 순위로 이양
+    SingleTransferableVote,
+    /// 찬반 투표 (Yes / No / Abstain / NoWithVeto), 기권은 정족수에만 포함
+    /// NoWithVeto 비율이 veto_threshold 를 넘으면 거부되고 보증금 소각, 없으면 0.334
+    SingleChoice { veto_threshold: Option<Decimal> },
+}
+
+/// 찬반 투표 선택지
+#[cw_serde]
+#[derive(Copy)]
+pub enum VoteOption {
+    Yes,
+    No,
+    Abstain,
+    NoWithVeto,
+}
+
+impl VoteOption {
+    pub const ALL: [VoteOption; 4] = [
+        VoteOption::Yes,
+        VoteOption::No,
+        VoteOption::Abstain,
+        VoteOption::NoWithVeto,
+    ];
+
+    /// 선택지의 후보 인덱스
+    pub fn candidate_id(&self) -> u64 {
+        match self {
+            VoteOption::Yes => 1,
+            VoteOption::No => 2,
+            VoteOption::Abstain => 3,
+            VoteOption::NoWithVeto => 4,
+        }
+    }
+}
+
+/// 후보에 배분한 credit (제곱근 투표)
+#[cw_serde]
+pub struct CreditAllocation {
+    /// 후보 인덱스
+    pub candidate_id: u64,
+    /// 배분한 credit
+    pub credits: u64,
+}
+
+/// 스테이킹 해제 후 반환 대기 중인 nft
+#[cw_serde]
+pub struct NftClaim {
+    /// 토큰 아이디
+    pub token_id: String,
+    /// 반환 가능 시점
+    pub release_at: Expiration,
 }
 
 /// 투표
@@ -53,8 +316,28 @@ pub struct Vote {
     pub voter: Addr,
     /// Propsal 인덱스
     pub propsal_id: u64,
-    /// Candidate 인덱스
+    /// Candidate 인덱스 (제곱근 투표는 credit 을 가장 많이 배분한 후보, 순위 투표는 1순위,
+    /// 승인 투표는 첫 번째 승인 후보)
     pub candidate_id: u64,
+    /// 투표에 사용된 NFT 토큰 아이디
+    pub token_ids: Vec<String>,
+    /// 후보별 credit 배분 (제곱근 투표)
+    pub credits: Vec<CreditAllocation>,
+    /// 후보 순위 (순위 투표)
+    pub ranking: Vec<u64>,
+    /// 승인한 후보 (승인 투표)
+    pub approvals: Vec<u64>,
+}
+
+impl Vote {
+    /// 투표가 세어지는 후보 (승인 투표는 승인한 모든 후보, 제곱근 투표는 credit 을 배분한 모든 후보)
+    pub fn candidate_ids(&self) -> Vec<u64> {
+        match (self.approvals.is_empty(), self.credits.is_empty()) {
+            (false, _) => self.approvals.clone(),
+            (true, false) => self.credits.iter().map(|c| c.candidate_id).collect(),
+            (true, true) => vec![self.candidate_id],
+        }
+    }
 }
 
 /// 투표 후보
@@ -64,6 +347,115 @@ pub struct Candidate {
     pub id: u64,
     /// 후보 이름
     pub name: String,
+    /// 통과 시 실행할 메시지
+    #[serde(default)]
+    pub msgs: Vec<CosmosMsg>,
+}
+
+/// 동점 처리 방식
+#[cw_serde]
+pub enum TieBreaker {
+    /// 후보 인덱스가 가장 작은 후보
+    LowestCandidateId,
+    /// 해당 득표에 가장 먼저 도달한 후보
+    EarliestToReach,
+}
+
+/// 후보 득표 집계
+#[cw_serde]
+#[derive(Default)]
+pub struct Tally {
+    /// 득표 power (제곱근 투표는 credit)
+    pub power: u64,
+    /// 득표 가중치 (제곱근 투표는 credit 제곱근의 합, 그 외에는 power 와 같음)
+    pub weight: Decimal,
+    /// 현재 득표 가중치 이상에 처음 도달한 투표 순번
+    pub reached_at: u64,
+}
+
+/// 후보별 득표
+#[cw_serde]
+pub struct CandidateTally {
+    /// 후보 인덱스
+    pub candidate_id: u64,
+    /// 후보 이름
+    pub name: String,
+    /// 득표 power (제곱근 투표는 credit)
+    pub power: u64,
+    /// 득표 가중치, 순위는 가중치 기준
+    pub weight: Decimal,
+}
+
+/// 결선 라운드 (순위 투표, STV)
+#[cw_serde]
+pub struct RunoffRound {
+    /// 라운드 번호, 1부터 시작
+    pub round: u32,
+    /// 남은 후보별 득표
+    pub counts: Vec<RoundCount>,
+    /// 이번 라운드에서 당선된 후보 (당선 순서)
+    pub elected: Vec<u64>,
+    /// 이번 라운드에서 탈락한 후보
+    pub eliminated: Option<u64>,
+    /// 당선자 잉여표, 탈락자 득표의 이양
+    pub transfers: Vec<VoteTransfer>,
+    /// 남은 후보가 순위에 없어 소진된 power
+    pub exhausted: Decimal,
+}
+
+/// 결선 라운드의 후보 득표
+#[cw_serde]
+pub struct RoundCount {
+    /// 후보 인덱스
+    pub candidate_id: u64,
+    /// 득표 power (STV 잉여표 이양으로 소수가 될 수 있음)
+    pub power: Decimal,
+}
+
+/// 득표 이양
+#[cw_serde]
+pub struct VoteTransfer {
+    /// 당선 또는 탈락한 후보
+    pub from: u64,
+    /// 다음 순위 후보, 없으면 소진
+    pub to: Option<u64>,
+    /// 이양된 power
+    pub power: Decimal,
+}
+
+/// 투표율
+#[cw_serde]
+pub struct Turnout {
+    /// 총 투표 power
+    pub total_power: u64,
+    /// 투표자 수
+    pub voter_count: u64,
+    /// nft 총 발행량 (비율 정족수인 경우)
+    pub total_supply: Option<u64>,
+}
+
+/// 제안 통과 여부
+#[cw_serde]
+pub enum PropsalResultStatus {
+    /// 통과
+    Passed,
+    /// 부결
+    Rejected,
+    /// 정족수 미달
+    QuorumNotMet,
+    /// NoWithVeto 비율 초과로 거부 (찬반 투표)
+    Vetoed,
+}
+
+/// 투표 결과 유형
+#[cw_serde]
+pub enum PropsalOutcome {
+    /// 당선자 결정
+    Winner {},
+    /// 동점으로 남은 자리의 당선자를 정하지 못함
+    Tie { candidate_ids: Vec<u64> },
+    /// 투표 없음
+    NoVotes {},
 }
 
 /// 투표 결과
@@ -71,12 +463,99 @@ pub struct Candidate {
 pub struct PropsalResult {
     /// 제안
     pub propsal: Propsal,
-    /// 최다 득표자
-    pub winner: Candidate,
+    /// 통과 여부
+    pub status: PropsalResultStatus,
+    /// 결과 유형
+    pub outcome: PropsalOutcome,
+    /// 당선자 (당선 순서)
+    pub winners: Vec<Candidate>,
+    /// 후보별 득표 (순위 투표, STV 는 1순위 득표)
+    pub tallies: Vec<CandidateTally>,
+    /// 결선 라운드 (순위 투표, STV), 그 외에는 비어 있음
+    pub rounds: Vec<RunoffRound>,
+    /// 당선 기준 득표 (STV 의 Droop quota)
+    pub quota: Option<Decimal>,
+    /// 투표율
+    pub turnout: Turnout,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+/// 컨트랙트 소유권
+pub const OWNERSHIP: Item<Ownership> = Item::new("ownership");
 pub const BLOCK_HEIGHTS: Map<u64, u64> = Map::new("block_record");
 pub const BLOCK_INDEX: Item<u64> = Item::new("block_index");
+/// 다음 제안 아이디
 pub const PROPSAL_INDEX: Item<u64> = Item::new("propsal_index");
+/// 생성된 제안 수
+pub const PROPSAL_COUNT: Item<u64> = Item::new("propsal_count");
 pub const PROPSALS: Map<u64, Propsal> = Map::new("propsals");
+/// 확정된 투표 결과
+pub const PROPSAL_RESULTS: Map<u64, PropsalResult> = Map::new("propsal_results");
+/// (propsal_id, candidate_id) -> 득표 집계
+pub const TALLIES: Map<(u64, u64), Tally> = Map::new("tallies");
+/// (propsal_id, candidate_id, 득표 가중치) -> 해당 득표 가중치에 처음 도달한 투표 순번
+pub const TALLY_LEVELS: Map<(u64, u64, u128), u64> = Map::new("tally_levels");
+/// (propsal_id, token_id) -> 투표자, 토큰당 한 번만 투표
+pub const VOTED_TOKENS: Map<(u64, &str), Addr> = Map::new("voted_tokens");
+/// (스테이커, token_id) -> 스테이킹된 거버넌스 nft
+pub const STAKED_NFTS: Map<(&Addr, &str), Empty> = Map::new("staked_nfts");
+/// 스테이커 -> 반환 대기 중인 nft
+pub const NFT_CLAIMS: Map<&Addr, Vec<NftClaim>> = Map::new("nft_claims");
+/// 주소별 스테이킹 power, 블록 높이별 스냅샷
+pub const STAKED_POWER: SnapshotMap<&Addr, u64> = SnapshotMap::new(
+    "staked_power",
+    "staked_power__checkpoints",
+    "staked_power__changelog",
+    Strategy::EveryBlock,
+);
+/// 전체 스테이킹 power, 블록 높이별 스냅샷
+pub const TOTAL_STAKED: SnapshotItem<u64> = SnapshotItem::new(
+    "total_staked",
+    "total_staked__checkpoints",
+    "total_staked__changelog",
+    Strategy::EveryBlock,
+);
+/// 위임자 -> 대리인, 블록 높이별 스냅샷
+pub const DELEGATES: SnapshotMap<&Addr, Addr> = SnapshotMap::new(
+    "delegates",
+    "delegates__checkpoints",
+    "delegates__changelog",
+    Strategy::EveryBlock,
+);
+/// 대리인별 위임받은 스테이킹 power, 블록 높이별 스냅샷
+pub const DELEGATED_POWER: SnapshotMap<&Addr, u64> = SnapshotMap::new(
+    "delegated_power",
+    "delegated_power__checkpoints",
+    "delegated_power__changelog",
+    Strategy::EveryBlock,
+);
+/// (대리인, 위임자) -> 현재 위임 목록
+pub const DELEGATIONS: Map<(&Addr, &Addr), Empty> = Map::new("delegations");
+/// (propsal_id, 대리인) -> 위임자가 직접 투표해 대리인 투표에서 빠진 power
+pub const OVERRIDDEN_POWER: Map<(u64, &Addr), u64> = Map::new("overridden_power");
+/// 주소 -> 부여된 역할
+pub const ROLES: Map<&Addr, Vec<Role>> = Map::new("roles");
+
+/// (propsal_id, candidate_id, voter) -> 후보에게 세어진 투표 (승인, credit 배분 후보 모두 포함)
+pub const CANDIDATE_VOTERS: Map<(u64, u64, &Addr), Empty> = Map::new("candidate_voters");
+
+/// 투표 보조 인덱스
+pub struct VoteIndexes<'a> {
+    /// 투표자별
+    pub voter: MultiIndex<'a, Addr, Vote, (u64, Addr)>,
+}
+
+impl<'a> IndexList<Vote> for VoteIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Vote>> + '_> {
+        let v: Vec<&dyn Index<Vote>> = vec![&self.voter];
+        Box::new(v.into_iter())
+    }
+}
+
+/// (propsal_id, voter) -> 투표, 투표자당 한 번만 투표
+pub fn votes<'a>() -> IndexedMap<'a, (u64, &'a Addr), Vote, VoteIndexes<'a>> {
+    let indexes = VoteIndexes {
+        voter: MultiIndex::new(|_pk, v| v.voter.clone(), "votes", "votes__voter"),
+    };
+    IndexedMap::new("votes", indexes)
+}
@@ -0,0 +1,389 @@
+use cosmwasm_std::{Decimal, Deps, Order, StdResult, Storage};
+use cw721::{Cw721QueryMsg, NumTokensResponse};
+use cw_storage_plus::Bound;
+
+use crate::runoff::{instant_runoff, single_transferable_vote};
+use crate::state::{
+    CandidateTally, PowerSource, Propsal, PropsalOutcome, PropsalResult, PropsalResultStatus,
+    Quorum, Tally, TieBreaker, Turnout, Vote, VoteOption, VotingStrategy, TALLIES, TALLY_LEVELS,
+    TOTAL_STAKED,
+};
+
+/// 후보 득표 증가
+pub fn add_tally(
+    storage: &mut dyn Storage,
+    propsal: &Propsal,
+    candidate_id: u64,
+    power: u64,
+) -> StdResult<()> {
+    add_weighted_tally(
+        storage,
+        propsal,
+        candidate_id,
+        power,
+        Decimal::from_ratio(power, 1u64),
+    )
+}
+
+/// 후보 득표 차감
+pub fn sub_tally(
+    storage: &mut dyn Storage,
+    propsal: &Propsal,
+    candidate_id: u64,
+    power: u64,
+) -> StdResult<()> {
+    sub_weighted_tally(
+        storage,
+        propsal,
+        candidate_id,
+        power,
+        Decimal::from_ratio(power, 1u64),
+    )
+}
+
+fn add_weighted_tally(
+    storage: &mut dyn Storage,
+    propsal: &Propsal,
+    candidate_id: u64,
+    power: u64,
+    weight: Decimal,
+) -> StdResult<()> {
+    let tally = TALLIES
+        .may_load(storage, (propsal.id, candidate_id))?
+        .unwrap_or_default();
+    let weight = tally.weight + weight;
+    // 득표 가중치별로 처음 도달한 투표 순번 기록
+    let level = (propsal.id, candidate_id, weight.atomics().u128());
+    if !weight.is_zero() && !TALLY_LEVELS.has(storage, level) {
+        TALLY_LEVELS.save(storage, level, &propsal.vote_count)?;
+    }
+
+    save_tally(storage, propsal, candidate_id, tally.power + power, weight)
+}
+
+fn sub_weighted_tally(
+    storage: &mut dyn Storage,
+    propsal: &Propsal,
+    candidate_id: u64,
+    power: u64,
+    weight: Decimal,
+) -> StdResult<()> {
+    let tally = TALLIES
+        .may_load(storage, (propsal.id, candidate_id))?
+        .unwrap_or_default();
+
+    save_tally(
+        storage,
+        propsal,
+        candidate_id,
+        tally.power - power,
+        tally.weight - weight,
+    )
+}
+
+/// 득표 저장, 도달 시점은 현재 득표 가중치 이상에 처음 도달한 투표 순번
+fn save_tally(
+    storage: &mut dyn Storage,
+    propsal: &Propsal,
+    candidate_id: u64,
+    power: u64,
+    weight: Decimal,
+) -> StdResult<()> {
+    let reached_at = match weight.is_zero() {
+        true => 0,
+        false => TALLY_LEVELS
+            .prefix((propsal.id, candidate_id))
+            .range(
+                storage,
+                Some(Bound::inclusive(weight.atomics().u128())),
+                None,
+                Order::Ascending,
+            )
+            .map(|item| item.map(|(_, reached_at)| reached_at))
+            .collect::<StdResult<Vec<_>>>()?
+            .into_iter()
+            .min()
+            .unwrap_or_default(),
+    };
+    TALLIES.save(
+        storage,
+        (propsal.id, candidate_id),
+        &Tally {
+            power,
+            weight,
+            reached_at,
+        },
+    )
+}
+
+/// credit 의 제곱근
+pub fn quadratic_weight(credits: u64) -> Decimal {
+    Decimal::from_ratio(credits, 1u64).sqrt()
+}
+
+/// 투표의 득표 반영
+pub fn add_vote_tally(storage: &mut dyn Storage, propsal: &Propsal, vote: &Vote) -> StdResult<()> {
+    match propsal.voting_strategy {
+        // 순위 투표, STV 는 1순위 득표
+        VotingStrategy::Plurality
+        | VotingStrategy::RankedChoice
+        | VotingStrategy::SingleTransferableVote
+        | VotingStrategy::SingleChoice { .. } => {
+            add_tally(storage, propsal, vote.candidate_id, vote.power)
+        }
+        VotingStrategy::Approval { .. } => vote
+            .approvals
+            .iter()
+            .try_for_each(|candidate_id| add_tally(storage, propsal, *candidate_id, vote.power)),
+        VotingStrategy::Quadratic => vote.credits.iter().try_for_each(|allocation| {
+            add_weighted_tally(
+                storage,
+                propsal,
+                allocation.candidate_id,
+                allocation.credits,
+                quadratic_weight(allocation.credits),
+            )
+        }),
+    }
+}
+
+/// 투표의 득표 취소
+pub fn sub_vote_tally(storage: &mut dyn Storage, propsal: &Propsal, vote: &Vote) -> StdResult<()> {
+    match propsal.voting_strategy {
+        VotingStrategy::Plurality
+        | VotingStrategy::RankedChoice
+        | VotingStrategy::SingleTransferableVote
+        | VotingStrategy::SingleChoice { .. } => {
+            sub_tally(storage, propsal, vote.candidate_id, vote.power)
+        }
+        VotingStrategy::Approval { .. } => vote
+            .approvals
+            .iter()
+            .try_for_each(|candidate_id| sub_tally(storage, propsal, *candidate_id, vote.power)),
+        VotingStrategy::Quadratic => vote.credits.iter().try_for_each(|allocation| {
+            sub_weighted_tally(
+                storage,
+                propsal,
+                allocation.candidate_id,
+                allocation.credits,
+                quadratic_weight(allocation.credits),
+            )
+        }),
+    }
+}
+
+/// 득표 집계로 투표 결과 계산
+pub fn compute_result(deps: Deps, propsal: &Propsal) -> StdResult<PropsalResult> {
+    let mut tallies = vec![];
+    let mut standings: Vec<Standing> = vec![];
+    for candidate in propsal.candidates.iter() {
+        let tally = TALLIES
+            .may_load(deps.storage, (propsal.id, candidate.id))?
+            .unwrap_or_default();
+        tallies.push(CandidateTally {
+            candidate_id: candidate.id,
+            name: candidate.name.clone(),
+            power: tally.power,
+            weight: tally.weight,
+        });
+        standings.push((candidate.id, tally.weight, tally));
+    }
+
+    // 당선자와 통과 기준에 쓰는 최다 득표 비율
+    let mut rounds = vec![];
+    let mut quota = None;
+    let mut threshold = propsal.threshold;
+    let mut vetoed = false;
+    let (outcome, winner_ids, top_share) = match propsal.voting_strategy {
+        // 순위 투표는 마지막 결선 라운드의 득표
+        VotingStrategy::RankedChoice => {
+            rounds = instant_runoff(deps.storage, propsal)?;
+            let mut standings = vec![];
+            for count in rounds
+                .last()
+                .map(|round| &round.counts)
+                .into_iter()
+                .flatten()
+            {
+                let tally = TALLIES
+                    .may_load(deps.storage, (propsal.id, count.candidate_id))?
+                    .unwrap_or_default();
+                standings.push((count.candidate_id, count.power, tally));
+            }
+            let active = standings.iter().map(|(_, weight, _)| *weight).sum();
+            let top_share = top_share(&standings, active);
+            let (outcome, winner_ids) = select_winners(propsal, standings);
+            (outcome, winner_ids, top_share)
+        }
+        // STV 는 quota 로 당선이 결정되므로 통과 기준을 적용하지 않음
+        VotingStrategy::SingleTransferableVote => {
+            (rounds, quota) = single_transferable_vote(deps.storage, propsal)?;
+            let winner_ids: Vec<u64> = rounds
+                .iter()
+                .flat_map(|round| round.elected.clone())
+                .collect();
+            let outcome = match winner_ids.is_empty() {
+                true => PropsalOutcome::NoVotes {},
+                false => PropsalOutcome::Winner {},
+            };
+            (outcome, winner_ids, None)
+        }
+        // 찬반 투표는 기권을 제외한 Yes 비율, 통과 기준이 없으면 과반
+        VotingStrategy::SingleChoice { veto_threshold } => {
+            let weight = |option: VoteOption| {
+                standings
+                    .iter()
+                    .find(|(id, _, _)| *id == option.candidate_id())
+                    .map(|(_, weight, _)| *weight)
+                    .unwrap_or_default()
+            };
+            let total: Decimal = standings.iter().map(|(_, weight, _)| *weight).sum();
+            let veto = weight(VoteOption::NoWithVeto);
+            vetoed =
+                !total.is_zero() && veto / total > veto_threshold.unwrap_or(Decimal::permille(334));
+            threshold = Some(threshold.unwrap_or(Decimal::percent(50)));
+
+            let yes = weight(VoteOption::Yes);
+            let voted = yes + weight(VoteOption::No) + veto;
+            match voted.is_zero() {
+                true => (PropsalOutcome::NoVotes {}, vec![], None),
+                false => (
+                    PropsalOutcome::Winner {},
+                    vec![VoteOption::Yes.candidate_id()],
+                    Some(yes / voted),
+                ),
+            }
+        }
+        _ => {
+            // 승인 투표는 한 투표가 여러 후보에 세어지므로 투표한 power 대비 비율
+            let total_weight = match propsal.voting_strategy {
+                VotingStrategy::Approval { .. } => Decimal::from_ratio(propsal.total_power, 1u64),
+                _ => tallies.iter().map(|tally| tally.weight).sum(),
+            };
+            let top_share = top_share(&standings, total_weight);
+            let (outcome, winner_ids) = select_winners(propsal, standings);
+            (outcome, winner_ids, top_share)
+        }
+    };
+    let mut winners = winner_ids
+        .iter()
+        .filter_map(|id| propsal.candidates.iter().find(|c| c.id == *id).cloned())
+        .collect::<Vec<_>>();
+
+    // 비율 정족수는 nft 총 발행량 기준 (스테이킹 제안은 등록 시점 총 스테이킹량)
+    let total_supply = match (&propsal.quorum, &propsal.power_source) {
+        (Some(Quorum::Percentage { .. }), PowerSource::Staked) => Some(
+            TOTAL_STAKED
+                .may_load_at_height(deps.storage, propsal.start_height)?
+                .unwrap_or_default(),
+        ),
+        (Some(Quorum::Percentage { .. }), PowerSource::TokenIds) => {
+            let res: NumTokensResponse = deps
+                .querier
+                .query_wasm_smart(&propsal.nft_address, &Cw721QueryMsg::NumTokens {})?;
+            Some(res.count)
+        }
+        _ => None,
+    };
+    let quorum_met = match &propsal.quorum {
+        None => true,
+        Some(Quorum::AbsolutePower { power }) => propsal.total_power >= *power,
+        Some(Quorum::Percentage { percentage }) => match total_supply {
+            Some(total_supply) if total_supply > 0 => {
+                Decimal::from_ratio(propsal.total_power, total_supply) >= *percentage
+            }
+            _ => percentage.is_zero(),
+        },
+    };
+
+    // 모든 자리의 당선자가 정해지고 최다 득표자의 득표 가중치 비율이 통과 기준 초과
+    // 찬반 투표는 NoWithVeto 비율이 기준을 넘으면 거부
+    let decided = outcome == PropsalOutcome::Winner {};
+    let status = match (quorum_met, decided, top_share) {
+        (false, _, _) => PropsalResultStatus::QuorumNotMet,
+        _ if vetoed => PropsalResultStatus::Vetoed,
+        (true, false, _) => PropsalResultStatus::Rejected,
+        (true, true, Some(share)) if threshold.is_some_and(|t| share <= t) => {
+            PropsalResultStatus::Rejected
+        }
+        (true, true, _) => PropsalResultStatus::Passed,
+    };
+
+    // 찬반 투표는 통과한 경우에만 Yes 를 당선자로 보고
+    if matches!(propsal.voting_strategy, VotingStrategy::SingleChoice { .. })
+        && status != PropsalResultStatus::Passed
+    {
+        winners.clear();
+    }
+
+    Ok(PropsalResult {
+        propsal: propsal.clone(),
+        status,
+        outcome,
+        winners,
+        tallies,
+        rounds,
+        quota,
+        turnout: Turnout {
+            total_power: propsal.total_power,
+            voter_count: propsal.voter_count,
+            total_supply,
+        },
+    })
+}
+
+/// (후보 인덱스, 득표 가중치, 득표 집계)
+type Standing = (u64, Decimal, Tally);
+
+/// 최다 득표 가중치의 비율
+fn top_share(standings: &[Standing], total: Decimal) -> Option<Decimal> {
+    let top = standings.iter().map(|(_, weight, _)| *weight).max()?;
+    match total.is_zero() {
+        true => None,
+        false => Some(top / total),
+    }
+}
+
+/// 득표 가중치 순으로 seats 명 선출
+///
+/// 마지막 자리에서 동점이면 동점 처리 방식으로 정하고, 방식이 없으면 동점 후보를
+/// 제외한 당선자만 반환한다.
+fn select_winners(propsal: &Propsal, mut standings: Vec<Standing>) -> (PropsalOutcome, Vec<u64>) {
+    standings.retain(|(_, weight, _)| !weight.is_zero());
+    standings.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let seats = propsal.seats.max(1) as usize;
+    if standings.is_empty() {
+        return (PropsalOutcome::NoVotes {}, vec![]);
+    }
+    if standings.len() <= seats {
+        let winner_ids = standings.iter().map(|(id, _, _)| *id).collect();
+        return (PropsalOutcome::Winner {}, winner_ids);
+    }
+
+    let cutoff = standings[seats - 1].1;
+    let mut winner_ids: Vec<u64> = standings
+        .iter()
+        .filter(|(_, weight, _)| *weight > cutoff)
+        .map(|(id, _, _)| *id)
+        .collect();
+    let mut tied: Vec<&Standing> = standings
+        .iter()
+        .filter(|(_, weight, _)| *weight == cutoff)
+        .collect();
+    let remaining = seats - winner_ids.len();
+    if tied.len() > remaining {
+        match propsal.tie_breaker {
+            Some(TieBreaker::LowestCandidateId) => tied.sort_by_key(|(id, _, _)| *id),
+            Some(TieBreaker::EarliestToReach) => {
+                tied.sort_by_key(|(id, _, tally)| (tally.reached_at, *id))
+            }
+            None => {
+                let candidate_ids = tied.iter().map(|(id, _, _)| *id).collect();
+                return (PropsalOutcome::Tie { candidate_ids }, winner_ids);
+            }
+        }
+    }
+    winner_ids.extend(tied.iter().take(remaining).map(|(id, _, _)| *id));
+
+    (PropsalOutcome::Winner {}, winner_ids)
+}